@@ -0,0 +1,173 @@
+-- Master data: skills & the things they were developed through
+CREATE TABLE IF NOT EXISTS SkillCategory (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+) STRICT;
+
+ALTER TABLE Skill ADD COLUMN category_id INTEGER
+    REFERENCES SkillCategory (id) ON DELETE SET NULL;
+
+CREATE TABLE IF NOT EXISTS Job (
+    id INTEGER PRIMARY KEY,
+    employer TEXT NOT NULL,
+    job_title TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    end_date TEXT
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS Project (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL
+) STRICT;
+
+-- a DescriptionItem belongs to exactly one of a Job or a Project
+CREATE TABLE IF NOT EXISTS DescriptionItem (
+    id INTEGER PRIMARY KEY,
+    job_id INTEGER,
+    project_id INTEGER,
+    position INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    FOREIGN KEY (job_id) REFERENCES Job (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES Project (id) ON DELETE CASCADE,
+    CHECK ((job_id IS NULL) <> (project_id IS NULL))
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS Degree (
+    id INTEGER PRIMARY KEY,
+    level TEXT NOT NULL,
+    major TEXT NOT NULL,
+    minor TEXT,
+    gpa REAL,
+    description TEXT
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS Course (
+    id INTEGER PRIMARY KEY,
+    degree_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    FOREIGN KEY (degree_id) REFERENCES Degree (id) ON DELETE CASCADE
+) STRICT;
+
+-- a SkillDeveloper is exactly one of a Job, Project, Degree, or Course, giving all of them a
+-- single id space to link developed skills against
+CREATE TABLE IF NOT EXISTS SkillDeveloper (
+    id INTEGER PRIMARY KEY,
+    job_id INTEGER UNIQUE,
+    project_id INTEGER UNIQUE,
+    degree_id INTEGER UNIQUE,
+    course_id INTEGER UNIQUE,
+    FOREIGN KEY (job_id) REFERENCES Job (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES Project (id) ON DELETE CASCADE,
+    FOREIGN KEY (degree_id) REFERENCES Degree (id) ON DELETE CASCADE,
+    FOREIGN KEY (course_id) REFERENCES Course (id) ON DELETE CASCADE,
+    CHECK (
+        (job_id IS NOT NULL)
+        + (project_id IS NOT NULL)
+        + (degree_id IS NOT NULL)
+        + (course_id IS NOT NULL)
+        = 1
+    )
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS DevelopedSkill (
+    skill_id INTEGER NOT NULL,
+    dev_id INTEGER NOT NULL,
+    PRIMARY KEY (skill_id, dev_id),
+    FOREIGN KEY (skill_id) REFERENCES Skill (id) ON DELETE CASCADE,
+    FOREIGN KEY (dev_id) REFERENCES SkillDeveloper (id) ON DELETE CASCADE
+) STRICT;
+
+-- Resumes & their sections
+CREATE TABLE IF NOT EXISTS Resume (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS HeaderSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS ContactMethod (
+    id INTEGER PRIMARY KEY,
+    header_id INTEGER NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('phone', 'email', 'link')),
+    country_code INTEGER,
+    number TEXT,
+    email_address TEXT,
+    display TEXT,
+    href TEXT,
+    FOREIGN KEY (header_id) REFERENCES HeaderSection (id) ON DELETE CASCADE,
+    CHECK (kind <> 'phone' OR (country_code IS NOT NULL AND number IS NOT NULL)),
+    CHECK (kind <> 'email' OR email_address IS NOT NULL),
+    CHECK (kind <> 'link' OR (display IS NOT NULL AND href IS NOT NULL))
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS SummarySection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    content TEXT NOT NULL,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS SkillsSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS SkillsSectionSkill (
+    section_id INTEGER NOT NULL,
+    skill_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, skill_id),
+    FOREIGN KEY (section_id) REFERENCES SkillsSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (skill_id) REFERENCES Skill (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS EducationSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS EducationSectionDegree (
+    section_id INTEGER NOT NULL,
+    degree_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, degree_id),
+    FOREIGN KEY (section_id) REFERENCES EducationSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (degree_id) REFERENCES Degree (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS ExperienceSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS ExperienceSectionJob (
+    section_id INTEGER NOT NULL,
+    job_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, job_id),
+    FOREIGN KEY (section_id) REFERENCES ExperienceSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (job_id) REFERENCES Job (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS ProjectSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS ProjectSectionProject (
+    section_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, project_id),
+    FOREIGN KEY (section_id) REFERENCES ProjectSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES Project (id) ON DELETE CASCADE
+) STRICT;
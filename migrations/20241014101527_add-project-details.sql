@@ -0,0 +1,4 @@
+ALTER TABLE Project ADD COLUMN repository TEXT;
+ALTER TABLE Project ADD COLUMN demo TEXT;
+ALTER TABLE Project ADD COLUMN start_date TEXT;
+ALTER TABLE Project ADD COLUMN end_date TEXT;
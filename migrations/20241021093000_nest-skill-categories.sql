@@ -0,0 +1,4 @@
+-- categories nest to form a taxonomy, e.g. Languages > Systems, with skills at the leaves;
+-- removing a category removes everything nested under it, leaving its skills uncategorized
+ALTER TABLE SkillCategory ADD COLUMN parent_id INTEGER
+    REFERENCES SkillCategory (id) ON DELETE CASCADE;
@@ -0,0 +1,29 @@
+-- other names a skill goes by, e.g. JS & ECMAScript for JavaScript
+CREATE TABLE IF NOT EXISTS SkillAlias (
+    id INTEGER PRIMARY KEY,
+    skill_id INTEGER NOT NULL,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    FOREIGN KEY (skill_id) REFERENCES Skill (id) ON DELETE CASCADE
+) STRICT;
+
+-- a name must refer to exactly one skill, whether as it's name or an alias
+CREATE TRIGGER IF NOT EXISTS skill_alias_not_a_skill_name
+BEFORE INSERT ON SkillAlias
+WHEN EXISTS (SELECT 1 FROM Skill WHERE name = NEW.name COLLATE NOCASE)
+BEGIN
+    SELECT RAISE(ABORT, 'alias is already the name of a skill');
+END;
+
+CREATE TRIGGER IF NOT EXISTS skill_name_not_an_alias
+BEFORE INSERT ON Skill
+WHEN EXISTS (SELECT 1 FROM SkillAlias WHERE name = NEW.name)
+BEGIN
+    SELECT RAISE(ABORT, 'skill name is already an alias of another skill');
+END;
+
+CREATE TRIGGER IF NOT EXISTS skill_rename_not_an_alias
+BEFORE UPDATE OF name ON Skill
+WHEN EXISTS (SELECT 1 FROM SkillAlias WHERE name = NEW.name)
+BEGIN
+    SELECT RAISE(ABORT, 'skill name is already an alias of another skill');
+END;
@@ -0,0 +1,119 @@
+-- Master data for the less common resume sections
+CREATE TABLE IF NOT EXISTS Certification (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    issuer TEXT NOT NULL,
+    issue_date TEXT NOT NULL,
+    expiry_date TEXT,
+    credential_id TEXT
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS Award (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    issuer TEXT,
+    award_date TEXT,
+    description TEXT
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS Publication (
+    id INTEGER PRIMARY KEY,
+    title TEXT NOT NULL,
+    publisher TEXT,
+    publish_date TEXT,
+    url TEXT
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS Volunteer (
+    id INTEGER PRIMARY KEY,
+    organization TEXT NOT NULL,
+    role TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    end_date TEXT,
+    description TEXT
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS Language (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    proficiency TEXT NOT NULL CHECK (
+        proficiency IN ('elementary', 'limited', 'professional', 'full-professional', 'native')
+    )
+) STRICT;
+
+-- Resume sections listing them
+
+CREATE TABLE IF NOT EXISTS CertificationSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS CertificationSectionCertification (
+    section_id INTEGER NOT NULL,
+    certification_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, certification_id),
+    FOREIGN KEY (section_id) REFERENCES CertificationSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (certification_id) REFERENCES Certification (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS AwardSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS AwardSectionAward (
+    section_id INTEGER NOT NULL,
+    award_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, award_id),
+    FOREIGN KEY (section_id) REFERENCES AwardSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (award_id) REFERENCES Award (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS PublicationSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS PublicationSectionPublication (
+    section_id INTEGER NOT NULL,
+    publication_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, publication_id),
+    FOREIGN KEY (section_id) REFERENCES PublicationSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (publication_id) REFERENCES Publication (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS VolunteerSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS VolunteerSectionVolunteer (
+    section_id INTEGER NOT NULL,
+    volunteer_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, volunteer_id),
+    FOREIGN KEY (section_id) REFERENCES VolunteerSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (volunteer_id) REFERENCES Volunteer (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS LanguageSection (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+
+CREATE TABLE IF NOT EXISTS LanguageSectionLanguage (
+    section_id INTEGER NOT NULL,
+    language_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (section_id, language_id),
+    FOREIGN KEY (section_id) REFERENCES LanguageSection (id) ON DELETE CASCADE,
+    FOREIGN KEY (language_id) REFERENCES Language (id) ON DELETE CASCADE
+) STRICT;
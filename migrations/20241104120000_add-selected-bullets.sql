@@ -0,0 +1,12 @@
+-- bullets a resume shows for the jobs & projects it lists, in it's own order & optionally
+-- reworded, without changing the master DescriptionItem
+CREATE TABLE IF NOT EXISTS SelectedBullet (
+    id INTEGER PRIMARY KEY,
+    resume_id INTEGER NOT NULL,
+    item_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    override_text TEXT,
+    UNIQUE (resume_id, item_id),
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE,
+    FOREIGN KEY (item_id) REFERENCES DescriptionItem (id) ON DELETE CASCADE
+) STRICT;
@@ -0,0 +1,12 @@
+-- alternative phrasings of a bullet, e.g. a short one or one for a technical audience
+CREATE TABLE IF NOT EXISTS DescriptionVariant (
+    id INTEGER PRIMARY KEY,
+    item_id INTEGER NOT NULL,
+    label TEXT NOT NULL COLLATE NOCASE,
+    text TEXT NOT NULL,
+    UNIQUE (item_id, label),
+    FOREIGN KEY (item_id) REFERENCES DescriptionItem (id) ON DELETE CASCADE
+) STRICT;
+
+-- label of the variant a resume shows for a selected bullet, instead of the bullet's own text
+ALTER TABLE SelectedBullet ADD COLUMN variant TEXT;
@@ -0,0 +1,15 @@
+-- every change saved to the db, grouped into the batch each save wrote, so saves can be undone
+CREATE TABLE IF NOT EXISTS History (
+    id INTEGER PRIMARY KEY,
+    batch INTEGER NOT NULL,
+    -- order of the change within it's batch
+    position INTEGER NOT NULL,
+    -- table the changed row lives in
+    model TEXT NOT NULL,
+    row_id INTEGER NOT NULL,
+    -- json snapshots of the row, before is null if the change added it & after if it removed it
+    before TEXT,
+    after TEXT,
+    undone INTEGER NOT NULL DEFAULT 0 CHECK (undone IN (0, 1)),
+    UNIQUE (batch, position)
+) STRICT;
@@ -0,0 +1,13 @@
+-- every change saved, in the form it was made, along with when & during which run of the app
+CREATE TABLE IF NOT EXISTS Journal (
+    id INTEGER PRIMARY KEY,
+    session TEXT NOT NULL,
+    recorded_at TEXT NOT NULL,
+    -- json of the change as it was made, e.g. an edit to a job
+    change TEXT NOT NULL
+) STRICT;
+
+-- the journalled change each row change was part of
+ALTER TABLE History ADD COLUMN journal_id INTEGER REFERENCES Journal (id);
+-- undone saves can't be redone once another save is made, but are kept for the journal
+ALTER TABLE History ADD COLUMN abandoned INTEGER NOT NULL DEFAULT 0 CHECK (abandoned IN (0, 1));
@@ -0,0 +1,7 @@
+-- counts every write to the db, so a run can tell whether anyone else wrote to it since it read
+CREATE TABLE IF NOT EXISTS Generation (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    value INTEGER NOT NULL
+) STRICT;
+
+INSERT INTO Generation (id, value) VALUES (1, 0);
@@ -0,0 +1,28 @@
+-- a resume exactly as it was exported, as the file that was written, so what was sent can be
+-- reproduced however the resume changes afterwards
+CREATE TABLE IF NOT EXISTS ResumeVersion (
+    id INTEGER PRIMARY KEY,
+    -- no foreign key, versions are kept even if the resume they were exported from isn't
+    resume_id INTEGER NOT NULL,
+    -- counts up from 1 for each resume
+    version INTEGER NOT NULL,
+    exported_at TEXT NOT NULL,
+    -- file type it was exported as
+    format TEXT NOT NULL,
+    -- the exported file, byte for byte
+    content BLOB NOT NULL,
+    -- sha256 of content, hex encoded
+    hash TEXT NOT NULL,
+    UNIQUE (resume_id, version)
+) STRICT;
+
+-- versions are a record of what was sent, so they can't be changed once made
+CREATE TRIGGER IF NOT EXISTS resume_version_no_update BEFORE UPDATE ON ResumeVersion
+BEGIN
+    SELECT RAISE(ABORT, 'Resume versions can''t be changed');
+END;
+
+CREATE TRIGGER IF NOT EXISTS resume_version_no_delete BEFORE DELETE ON ResumeVersion
+BEGIN
+    SELECT RAISE(ABORT, 'Resume versions can''t be deleted');
+END;
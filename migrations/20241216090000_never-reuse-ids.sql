@@ -0,0 +1,307 @@
+-- rows used to be given the lowest unused id above the rest, so deleting the newest row of a table
+-- let the next one added reuse it's id, mixing the deleted row's history & exported versions in
+-- with the new one's. Every table holding a model is rebuilt w/ AUTOINCREMENT ids, which are never
+-- reused, following https://www.sqlite.org/lang_altertable.html#otheralter
+--
+-- relies on foreign keys being off while migrating, so dropping the old tables doesn't cascade to
+-- the rows referencing them
+
+-- refers to a table being rebuilt, which can't be renamed into place while anything refers to it
+DROP TRIGGER skill_alias_not_a_skill_name;
+
+CREATE TABLE Resume_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL
+) STRICT;
+INSERT INTO Resume_new SELECT * FROM Resume;
+DROP TABLE Resume;
+ALTER TABLE Resume_new RENAME TO Resume;
+
+CREATE TABLE HeaderSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO HeaderSection_new SELECT * FROM HeaderSection;
+DROP TABLE HeaderSection;
+ALTER TABLE HeaderSection_new RENAME TO HeaderSection;
+
+CREATE TABLE ContactMethod_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    header_id INTEGER NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('phone', 'email', 'link')),
+    country_code INTEGER,
+    number TEXT,
+    email_address TEXT,
+    display TEXT,
+    href TEXT,
+    FOREIGN KEY (header_id) REFERENCES HeaderSection (id) ON DELETE CASCADE,
+    CHECK (kind <> 'phone' OR (country_code IS NOT NULL AND number IS NOT NULL)),
+    CHECK (kind <> 'email' OR email_address IS NOT NULL),
+    CHECK (kind <> 'link' OR (display IS NOT NULL AND href IS NOT NULL))
+) STRICT;
+INSERT INTO ContactMethod_new SELECT * FROM ContactMethod;
+DROP TABLE ContactMethod;
+ALTER TABLE ContactMethod_new RENAME TO ContactMethod;
+
+CREATE TABLE SummarySection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    content TEXT NOT NULL,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO SummarySection_new SELECT * FROM SummarySection;
+DROP TABLE SummarySection;
+ALTER TABLE SummarySection_new RENAME TO SummarySection;
+
+CREATE TABLE SkillCategory_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    parent_id INTEGER REFERENCES SkillCategory (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO SkillCategory_new SELECT * FROM SkillCategory;
+DROP TABLE SkillCategory;
+ALTER TABLE SkillCategory_new RENAME TO SkillCategory;
+
+CREATE TABLE Skill_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    category_id INTEGER REFERENCES SkillCategory (id) ON DELETE SET NULL
+) STRICT;
+INSERT INTO Skill_new SELECT * FROM Skill;
+DROP TABLE Skill;
+ALTER TABLE Skill_new RENAME TO Skill;
+
+CREATE TABLE SkillsSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO SkillsSection_new SELECT * FROM SkillsSection;
+DROP TABLE SkillsSection;
+ALTER TABLE SkillsSection_new RENAME TO SkillsSection;
+
+CREATE TABLE Job_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    employer TEXT NOT NULL,
+    job_title TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    end_date TEXT
+) STRICT;
+INSERT INTO Job_new SELECT * FROM Job;
+DROP TABLE Job;
+ALTER TABLE Job_new RENAME TO Job;
+
+CREATE TABLE Project_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    repository TEXT,
+    demo TEXT,
+    start_date TEXT,
+    end_date TEXT
+) STRICT;
+INSERT INTO Project_new SELECT * FROM Project;
+DROP TABLE Project;
+ALTER TABLE Project_new RENAME TO Project;
+
+CREATE TABLE Degree_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    level TEXT NOT NULL,
+    major TEXT NOT NULL,
+    minor TEXT,
+    gpa REAL,
+    description TEXT
+) STRICT;
+INSERT INTO Degree_new SELECT * FROM Degree;
+DROP TABLE Degree;
+ALTER TABLE Degree_new RENAME TO Degree;
+
+CREATE TABLE Course_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    degree_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    FOREIGN KEY (degree_id) REFERENCES Degree (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO Course_new SELECT * FROM Course;
+DROP TABLE Course;
+ALTER TABLE Course_new RENAME TO Course;
+
+CREATE TABLE DescriptionItem_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_id INTEGER,
+    project_id INTEGER,
+    position INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    FOREIGN KEY (job_id) REFERENCES Job (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES Project (id) ON DELETE CASCADE,
+    CHECK ((job_id IS NULL) <> (project_id IS NULL))
+) STRICT;
+INSERT INTO DescriptionItem_new SELECT * FROM DescriptionItem;
+DROP TABLE DescriptionItem;
+ALTER TABLE DescriptionItem_new RENAME TO DescriptionItem;
+
+CREATE TABLE SelectedBullet_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL,
+    item_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    override_text TEXT, variant TEXT,
+    UNIQUE (resume_id, item_id),
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE,
+    FOREIGN KEY (item_id) REFERENCES DescriptionItem (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO SelectedBullet_new SELECT * FROM SelectedBullet;
+DROP TABLE SelectedBullet;
+ALTER TABLE SelectedBullet_new RENAME TO SelectedBullet;
+
+CREATE TABLE EducationSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO EducationSection_new SELECT * FROM EducationSection;
+DROP TABLE EducationSection;
+ALTER TABLE EducationSection_new RENAME TO EducationSection;
+
+CREATE TABLE ExperienceSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO ExperienceSection_new SELECT * FROM ExperienceSection;
+DROP TABLE ExperienceSection;
+ALTER TABLE ExperienceSection_new RENAME TO ExperienceSection;
+
+CREATE TABLE ProjectSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO ProjectSection_new SELECT * FROM ProjectSection;
+DROP TABLE ProjectSection;
+ALTER TABLE ProjectSection_new RENAME TO ProjectSection;
+
+CREATE TABLE Certification_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    issuer TEXT NOT NULL,
+    issue_date TEXT NOT NULL,
+    expiry_date TEXT,
+    credential_id TEXT
+) STRICT;
+INSERT INTO Certification_new SELECT * FROM Certification;
+DROP TABLE Certification;
+ALTER TABLE Certification_new RENAME TO Certification;
+
+CREATE TABLE CertificationSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO CertificationSection_new SELECT * FROM CertificationSection;
+DROP TABLE CertificationSection;
+ALTER TABLE CertificationSection_new RENAME TO CertificationSection;
+
+CREATE TABLE Award_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    issuer TEXT,
+    award_date TEXT,
+    description TEXT
+) STRICT;
+INSERT INTO Award_new SELECT * FROM Award;
+DROP TABLE Award;
+ALTER TABLE Award_new RENAME TO Award;
+
+CREATE TABLE AwardSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO AwardSection_new SELECT * FROM AwardSection;
+DROP TABLE AwardSection;
+ALTER TABLE AwardSection_new RENAME TO AwardSection;
+
+CREATE TABLE Publication_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    title TEXT NOT NULL,
+    publisher TEXT,
+    publish_date TEXT,
+    url TEXT
+) STRICT;
+INSERT INTO Publication_new SELECT * FROM Publication;
+DROP TABLE Publication;
+ALTER TABLE Publication_new RENAME TO Publication;
+
+CREATE TABLE PublicationSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO PublicationSection_new SELECT * FROM PublicationSection;
+DROP TABLE PublicationSection;
+ALTER TABLE PublicationSection_new RENAME TO PublicationSection;
+
+CREATE TABLE Volunteer_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    organization TEXT NOT NULL,
+    role TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    end_date TEXT,
+    description TEXT
+) STRICT;
+INSERT INTO Volunteer_new SELECT * FROM Volunteer;
+DROP TABLE Volunteer;
+ALTER TABLE Volunteer_new RENAME TO Volunteer;
+
+CREATE TABLE VolunteerSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO VolunteerSection_new SELECT * FROM VolunteerSection;
+DROP TABLE VolunteerSection;
+ALTER TABLE VolunteerSection_new RENAME TO VolunteerSection;
+
+CREATE TABLE Language_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    proficiency TEXT NOT NULL CHECK (
+        proficiency IN ('elementary', 'limited', 'professional', 'full-professional', 'native')
+    )
+) STRICT;
+INSERT INTO Language_new SELECT * FROM Language;
+DROP TABLE Language;
+ALTER TABLE Language_new RENAME TO Language;
+
+CREATE TABLE LanguageSection_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    resume_id INTEGER NOT NULL UNIQUE,
+    FOREIGN KEY (resume_id) REFERENCES Resume (id) ON DELETE CASCADE
+) STRICT;
+INSERT INTO LanguageSection_new SELECT * FROM LanguageSection;
+DROP TABLE LanguageSection;
+ALTER TABLE LanguageSection_new RENAME TO LanguageSection;
+
+-- dropped along with the table they were on, or above
+CREATE TRIGGER skill_alias_not_a_skill_name
+BEFORE INSERT ON SkillAlias
+WHEN EXISTS (SELECT 1 FROM Skill WHERE name = NEW.name COLLATE NOCASE)
+BEGIN
+    SELECT RAISE(ABORT, 'alias is already the name of a skill');
+END;
+
+CREATE TRIGGER skill_name_not_an_alias
+BEFORE INSERT ON Skill
+WHEN EXISTS (SELECT 1 FROM SkillAlias WHERE name = NEW.name)
+BEGIN
+    SELECT RAISE(ABORT, 'skill name is already an alias of another skill');
+END;
+
+CREATE TRIGGER skill_rename_not_an_alias
+BEFORE UPDATE OF name ON Skill
+WHEN EXISTS (SELECT 1 FROM SkillAlias WHERE name = NEW.name)
+BEGIN
+    SELECT RAISE(ABORT, 'skill name is already an alias of another skill');
+END;
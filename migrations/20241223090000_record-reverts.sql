@@ -0,0 +1,3 @@
+-- rows put back by undoing or redoing a save, recorded so the journal shows what they did, but
+-- never themselves undone or redone
+ALTER TABLE History ADD COLUMN revert INTEGER NOT NULL DEFAULT 0 CHECK (revert IN (0, 1));
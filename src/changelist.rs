@@ -4,28 +4,109 @@
 //! will transform one [`state::State`] into the other.
 //! This means a `ChangeList` is a series of atomic state change operations to which items are
 //! typically added by prepending to the head & removed by popping the most recently added value.
+use std::marker::PhantomData;
+
+/// A stack of changes, applied oldest first.
+///
+/// Changes are kept as trait objects, by default only able to be applied to a state. A list may
+/// hold some richer kind of change instead, so long as it can still be applied to a state.
 #[derive(Debug)]
-pub struct ChangeList<SomeState: Clone, Index>(Vec<Box<dyn ApplyTo<SomeState, Index>>>);
+pub struct ChangeList<SomeState, Index, Change = dyn ApplyTo<SomeState, Index>>(
+    Vec<Box<Change>>,
+    PhantomData<fn(&mut SomeState) -> Index>,
+)
+where
+    SomeState: Clone,
+    Change: ApplyTo<SomeState, Index> + ?Sized;
 
-impl<SomeState: Clone, Index> ChangeList<SomeState, Index> {
+impl<SomeState, Index, Change> ChangeList<SomeState, Index, Change>
+where
+    SomeState: Clone,
+    Change: ApplyTo<SomeState, Index> + ?Sized,
+{
     pub fn new() -> Self {
-        Self(Vec::new())
+        Self(Vec::new(), PhantomData)
     }
 
     pub fn push<C>(&mut self, change: C)
     where
-        C: ApplyTo<SomeState, Index> + 'static,
+        C: IntoBoxed<Change>,
     {
-        self.0.push(Box::new(change))
+        self.0.push(change.into_boxed())
     }
 
-    fn pop_into(&mut self, other: &mut ChangeList<SomeState, Index>) {
-        if let Some(change) = self.0.pop() {
-            other.0.push(change)
+    /// Iterate over the changes, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Change> {
+        self.0.iter().map(|change| &**change)
+    }
+
+    /// Move the most recent change onto the top of another list, returning it.
+    fn pop_into<'a>(
+        &mut self,
+        other: &'a mut ChangeList<SomeState, Index, Change>,
+    ) -> Option<&'a Change> {
+        let change = self.0.pop()?;
+        other.0.push(change);
+        other.0.last().map(|change| &**change)
+    }
+
+    /// Undo the most recent change, moving it to `undone` so it can be redone later.
+    ///
+    /// `state` must be the result of applying every change in the list to `initial`, as the state
+    /// just before the change is rebuilt from it to work out what the change altered.
+    pub fn undo_into(
+        &mut self,
+        undone: &mut ChangeList<SomeState, Index, Change>,
+        initial: &SomeState,
+        state: &mut SomeState,
+    ) -> Option<Index> {
+        let change = self.pop_into(undone)?;
+        let mut before = initial.clone();
+        self.apply_all(&mut before);
+        let inverse = change.inverse(&before);
+        Some((*inverse).apply_to(state))
+    }
+
+    /// Redo the change most recently moved to `undone`, putting it back onto this list.
+    pub fn redo_from(
+        &mut self,
+        undone: &mut ChangeList<SomeState, Index, Change>,
+        state: &mut SomeState,
+    ) -> Option<Index> {
+        let change = undone.pop_into(self)?;
+        Some(change.apply_to(state))
+    }
+
+    /// Fold changes into earlier ones they can be combined with, leaving fewer changes with the
+    /// same effect on `initial`, which every change in the list must apply to.
+    ///
+    /// Each change is offered to the changes before it, newest first, until one absorbs it or it
+    /// can't be moved back any further.
+    pub fn compact(&mut self, initial: &SomeState)
+    where
+        Change: Compact<Index>,
+    {
+        let mut state = initial.clone();
+        let mut kept: Vec<(Box<Change>, Index)> = Vec::new();
+        'changes: for change in self.0.drain(..) {
+            let index = (*change).apply_to(&mut state);
+            for (earlier, earlier_index) in kept.iter_mut().rev() {
+                match earlier.absorb(earlier_index, &change) {
+                    Absorbed::Blocked => break,
+                    Absorbed::Passed => continue,
+                    Absorbed::Into(merged) => {
+                        *earlier = merged;
+                        continue 'changes;
+                    }
+                }
+            }
+            kept.push((change, index));
         }
+
+        self.0 = kept.into_iter().map(|(change, _)| change).collect();
     }
 
-    fn apply_all(&self, state: &mut SomeState) -> Vec<Index> {
+    pub fn apply_all(&self, state: &mut SomeState) -> Vec<Index> {
         self.apply_all_from(state, 0)
     }
 
@@ -40,12 +121,49 @@ impl<SomeState: Clone, Index> ChangeList<SomeState, Index> {
     }
 }
 
+/// Put a change in the box a [`ChangeList`] holding `Change`s keeps it in.
+pub trait IntoBoxed<Change: ?Sized> {
+    fn into_boxed(self) -> Box<Change>;
+}
+
+impl<S, I, C> IntoBoxed<dyn ApplyTo<S, I>> for C
+where
+    C: ApplyTo<S, I> + 'static,
+{
+    fn into_boxed(self) -> Box<dyn ApplyTo<S, I>> {
+        Box::new(self)
+    }
+}
+
+/// A change that can absorb later changes, for compacting a [`ChangeList`].
+pub trait Compact<Index> {
+    /// Fold a later change into this one, given the index this one was applied under.
+    fn absorb(&self, index: &Index, later: &Self) -> Absorbed<Self>;
+}
+
+/// What came of folding a later change into an earlier one.
+pub enum Absorbed<Change: ?Sized> {
+    /// the later change can't be folded into this one, nor moved back before it
+    Blocked,
+    /// the later change can't be folded into this one, but doesn't depend on it either, so could
+    /// be folded into a change before it
+    Passed,
+    /// this change has the same effect as both did
+    Into(Box<Change>),
+}
+
 pub trait ApplyTo<SomeState, Index>: std::fmt::Debug {
     fn apply_to(&self, state: &mut SomeState) -> Index;
+
+    /// The change that puts a state back the way it was `before` this change was applied to it.
+    fn inverse(&self, before: &SomeState) -> Box<dyn ApplyTo<SomeState, Index>>;
 }
 
 pub trait Apply<Change, Index> {
     fn apply(&mut self, change: &Change) -> Index;
+
+    /// The change that undoes applying `change` to this state.
+    fn inverse(&self, change: &Change) -> Box<dyn ApplyTo<Self, Index>>;
 }
 
 impl<S, I, C> ApplyTo<S, I> for C
@@ -56,6 +174,10 @@ where
     fn apply_to(&self, state: &mut S) -> I {
         S::apply(state, self)
     }
+
+    fn inverse(&self, before: &S) -> Box<dyn ApplyTo<S, I>> {
+        <S as Apply<C, I>>::inverse(before, self)
+    }
 }
 
 #[cfg(test)]
@@ -75,12 +197,12 @@ mod test_add_one {
 
     #[async_trait]
     impl Get<String, usize> for Vec<String> {
-        async fn get(&self, key: &usize) -> Option<&String> {
-            if *key < self.len() {
-                Some(&self[*key])
-            } else {
-                None
-            }
+        async fn get(
+            &mut self,
+            _conn: &mut sqlx::SqliteConnection,
+            key: &usize,
+        ) -> anyhow::Result<Option<&String>> {
+            Ok(self.as_slice().get(*key))
         }
     }
 
@@ -89,6 +211,24 @@ mod test_add_one {
             state.push(self.clone());
             state.len() - 1
         }
+
+        fn inverse(&self, _before: &Vec<String>) -> Box<dyn ApplyTo<Vec<String>, usize>> {
+            Box::new(RemoveLast)
+        }
+    }
+
+    #[derive(Debug)]
+    struct RemoveLast;
+
+    impl ApplyTo<Vec<String>, usize> for RemoveLast {
+        fn apply_to(&self, state: &mut Vec<String>) -> usize {
+            state.pop();
+            state.len()
+        }
+
+        fn inverse(&self, before: &Vec<String>) -> Box<dyn ApplyTo<Vec<String>, usize>> {
+            Box::new(before.last().cloned().unwrap_or_default())
+        }
     }
 
     #[test]
@@ -132,6 +272,10 @@ mod test_add_one {
             state[self.0] = self.1.clone();
             self.0
         }
+
+        fn inverse(&self, before: &Vec<String>) -> Box<dyn ApplyTo<Vec<String>, usize>> {
+            Box::new((self.0, before[self.0].clone()))
+        }
     }
 
     #[test]
@@ -151,4 +295,26 @@ mod test_add_one {
             "applying {changes:?} to {start:?} should create new state of {expected:?}, not {end:?}"
         );
     }
+
+    #[test]
+    fn undo_then_redo() {
+        let start: Vec<String> = vec!["existing".into()];
+        let mut changes: ChangeList<Vec<String>, usize> = ChangeList::new();
+        changes.push("new!".to_string());
+        changes.push((0, "altered!".to_string()));
+        let mut state = start.clone();
+        changes.apply_all(&mut state);
+        let mut undone = ChangeList::new();
+
+        changes.undo_into(&mut undone, &start, &mut state);
+        assert_eq!(state, vec!["existing", "new!"]);
+        changes.undo_into(&mut undone, &start, &mut state);
+        assert_eq!(state, start);
+        assert_eq!(changes.undo_into(&mut undone, &start, &mut state), None);
+
+        changes.redo_from(&mut undone, &mut state);
+        changes.redo_from(&mut undone, &mut state);
+        assert_eq!(state, vec!["altered!", "new!"]);
+        assert_eq!(changes.redo_from(&mut undone, &mut state), None);
+    }
 }
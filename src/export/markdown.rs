@@ -0,0 +1,232 @@
+use std::io::Write;
+
+use chrono::NaiveDate;
+
+use super::Document;
+use crate::model::ContactMethod;
+
+/// Write the document as markdown, leaving out any empty sections.
+pub fn write(document: &Document, writer: &mut impl Write) -> anyhow::Result<()> {
+    writeln!(writer, "# {}", document.name)?;
+    if !document.contacts.is_empty() {
+        let contacts: Vec<_> = document.contacts.iter().map(contact).collect();
+        writeln!(writer, "\n{}", contacts.join(" | "))?;
+    }
+
+    if let Some(summary) = &document.summary {
+        writeln!(writer, "\n## Summary\n\n{summary}")?;
+    }
+
+    if !document.skills.is_empty() {
+        writeln!(writer, "\n## Skills\n")?;
+        for group in &document.skills {
+            let category = match group.category.is_empty() {
+                true => "Other".to_string(),
+                false => group.category.join(" > "),
+            };
+            writeln!(writer, "- **{category}:** {}", group.skills.join(", "))?;
+        }
+    }
+
+    if !document.experience.is_empty() {
+        writeln!(writer, "\n## Experience")?;
+        for entry in &document.experience {
+            let job = &entry.job;
+            writeln!(writer, "\n### {}, {}\n", job.title, job.employer)?;
+            writeln!(writer, "{}", dates(Some(job.start), job.end))?;
+            write_bullets(writer, &entry.bullets)?;
+        }
+    }
+
+    if !document.education.is_empty() {
+        writeln!(writer, "\n## Education")?;
+        for entry in &document.education {
+            let degree = &entry.degree;
+            write!(writer, "\n### {} in {}", degree.level, degree.major)?;
+            if let Some(minor) = &degree.minor {
+                write!(writer, ", minor in {minor}")?;
+            }
+            writeln!(writer)?;
+            if let Some(gpa) = degree.gpa {
+                writeln!(writer, "\nGPA: {gpa:.2}")?;
+            }
+            if let Some(description) = &degree.description {
+                writeln!(writer, "\n{description}")?;
+            }
+            if !entry.courses.is_empty() {
+                writeln!(writer, "\nCourses: {}", entry.courses.join(", "))?;
+            }
+        }
+    }
+
+    if !document.projects.is_empty() {
+        writeln!(writer, "\n## Projects")?;
+        for entry in &document.projects {
+            let project = &entry.project;
+            writeln!(writer, "\n### {}", project.name)?;
+            if project.start.is_some() || project.end.is_some() {
+                writeln!(writer, "\n{}", dates(project.start, project.end))?;
+            }
+            let links: Vec<_> = [("source", &project.repository), ("demo", &project.demo)]
+                .into_iter()
+                .filter_map(|(text, url)| url.as_ref().map(|url| format!("[{text}]({url})")))
+                .collect();
+            if !links.is_empty() {
+                writeln!(writer, "\n{}", links.join(" | "))?;
+            }
+            write_bullets(writer, &entry.bullets)?;
+        }
+    }
+
+    if !document.certifications.is_empty() {
+        writeln!(writer, "\n## Certifications\n")?;
+        for certification in &document.certifications {
+            write!(
+                writer,
+                "- **{}**, {}, {}",
+                certification.name,
+                certification.issuer,
+                month(certification.issued)
+            )?;
+            if let Some(expires) = certification.expires {
+                write!(writer, " (expires {})", month(expires))?;
+            }
+            if let Some(credential_id) = &certification.credential_id {
+                write!(writer, ", credential id {credential_id}")?;
+            }
+            writeln!(writer)?;
+        }
+    }
+
+    if !document.awards.is_empty() {
+        writeln!(writer, "\n## Awards\n")?;
+        for award in &document.awards {
+            write!(writer, "- **{}**", award.name)?;
+            if let Some(issuer) = &award.issuer {
+                write!(writer, ", {issuer}")?;
+            }
+            if let Some(awarded) = award.awarded {
+                write!(writer, ", {}", month(awarded))?;
+            }
+            if let Some(description) = &award.description {
+                write!(writer, ": {description}")?;
+            }
+            writeln!(writer)?;
+        }
+    }
+
+    if !document.publications.is_empty() {
+        writeln!(writer, "\n## Publications\n")?;
+        for publication in &document.publications {
+            match &publication.url {
+                Some(url) => write!(writer, "- [{}]({url})", publication.title)?,
+                None => write!(writer, "- {}", publication.title)?,
+            }
+            if let Some(publisher) = &publication.publisher {
+                write!(writer, ", _{publisher}_")?;
+            }
+            if let Some(published) = publication.published {
+                write!(writer, ", {}", month(published))?;
+            }
+            writeln!(writer)?;
+        }
+    }
+
+    if !document.volunteering.is_empty() {
+        writeln!(writer, "\n## Volunteering")?;
+        for volunteer in &document.volunteering {
+            writeln!(
+                writer,
+                "\n### {}, {}\n",
+                volunteer.role, volunteer.organization
+            )?;
+            writeln!(writer, "{}", dates(Some(volunteer.start), volunteer.end))?;
+            if let Some(description) = &volunteer.description {
+                writeln!(writer, "\n{description}")?;
+            }
+        }
+    }
+
+    if !document.languages.is_empty() {
+        writeln!(writer, "\n## Languages\n")?;
+        for language in &document.languages {
+            writeln!(writer, "- **{}:** {}", language.name, language.proficiency)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn contact(method: &ContactMethod) -> String {
+    format!("[{method}]({})", method.href())
+}
+
+fn month(date: NaiveDate) -> String {
+    date.format("%b %Y").to_string()
+}
+
+fn dates(start: Option<NaiveDate>, end: Option<NaiveDate>) -> String {
+    match (start, end) {
+        (Some(start), Some(end)) => format!("{} - {}", month(start), month(end)),
+        (Some(start), None) => format!("{} - Present", month(start)),
+        (None, Some(end)) => format!("Until {}", month(end)),
+        (None, None) => String::new(),
+    }
+}
+
+fn write_bullets(writer: &mut impl Write, bullets: &[String]) -> anyhow::Result<()> {
+    if !bullets.is_empty() {
+        writeln!(writer)?;
+    }
+    for bullet in bullets {
+        writeln!(writer, "- {bullet}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[test]
+fn skill_groups_are_labelled_with_category_path() {
+    use super::SkillGroup;
+
+    let document = Document {
+        name: "Me".into(),
+        contacts: vec![ContactMethod::Email("me@example.com".into())],
+        summary: None,
+        skills: vec![
+            SkillGroup {
+                category: vec!["Languages".into(), "Systems".into()],
+                skills: vec!["Rust".into(), "C".into()],
+            },
+            SkillGroup {
+                category: Vec::new(),
+                skills: vec!["Bash".into()],
+            },
+        ],
+        education: Vec::new(),
+        experience: Vec::new(),
+        projects: Vec::new(),
+        certifications: Vec::new(),
+        awards: Vec::new(),
+        publications: Vec::new(),
+        volunteering: Vec::new(),
+        languages: Vec::new(),
+    };
+    let mut out = Vec::new();
+
+    write(&document, &mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "# Me
+
+[me@example.com](mailto:me@example.com)
+
+## Skills
+
+- **Languages > Systems:** Rust, C
+- **Other:** Bash
+"
+    );
+}
@@ -0,0 +1,395 @@
+//! Turning a resume & everything it references into a file.
+//!
+//! Exporting happens in two steps: first a [`Document`] is loaded, resolving every section of a
+//! resume into the plain text & dates it will display, then the document is written out in the
+//! requested [`Format`]. Formats only ever see a `Document`, so they don't need to know anything
+//! about how the data is stored.
+//!
+//! Every file exported is also kept as a [`Version`] of it's resume, so exactly what was sent can
+//! be exported again later, however the resume has changed since.
+use std::io::Write;
+
+use clap::ValueEnum;
+use sqlx::SqliteConnection;
+
+use crate::model::{self, DbModel, ListedSection, Parent};
+use crate::state::Key;
+
+mod markdown;
+mod version;
+
+pub use version::Version;
+
+/// File types a resume can be exported as.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Markdown,
+}
+
+impl Format {
+    pub fn write(&self, document: &Document, writer: &mut impl Write) -> anyhow::Result<()> {
+        match self {
+            Format::Markdown => markdown::write(document, writer),
+        }
+    }
+}
+
+/// A resume with every section resolved into what will be displayed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    /// Name at the top of the resume, taken from the header if there is one
+    pub name: String,
+    pub contacts: Vec<model::ContactMethod>,
+    pub summary: Option<String>,
+    pub skills: Vec<SkillGroup>,
+    pub education: Vec<DegreeEntry>,
+    pub experience: Vec<JobEntry>,
+    pub projects: Vec<ProjectEntry>,
+    pub certifications: Vec<model::Certification>,
+    pub awards: Vec<model::Award>,
+    pub publications: Vec<model::Publication>,
+    pub volunteering: Vec<model::Volunteer>,
+    pub languages: Vec<model::Language>,
+}
+
+/// Skills from a resume's skills section that share a category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillGroup {
+    /// Names of the category & each of it's ancestors, starting from the top of the taxonomy;
+    /// empty for skills without a category
+    pub category: Vec<String>,
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DegreeEntry {
+    pub degree: model::Degree,
+    pub courses: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobEntry {
+    pub job: model::Job,
+    pub bullets: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectEntry {
+    pub project: model::Project,
+    pub bullets: Vec<String>,
+}
+
+impl Document {
+    /// Resolve the resume with the given id & each of it's sections.
+    pub async fn load(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let resume = model::Resume::get_one_by_id(&mut *conn, id).await?;
+        let key = Key::Db(id);
+
+        let (name, contacts) = match model::HeaderSection::get_one_for(&mut *conn, &key).await? {
+            Some((header, section)) => {
+                let contacts = model::Contact::get_all_for(&mut *conn, &Key::Db(header)).await?;
+                (
+                    section.name,
+                    contacts.into_iter().map(|(_, c)| c.method).collect(),
+                )
+            }
+            None => (resume.name, Vec::new()),
+        };
+        let summary = model::SummarySection::get_one_for(&mut *conn, &key)
+            .await?
+            .map(|(_, section)| section.content);
+
+        let skills = match model::SkillsSection::get_one_for(&mut *conn, &key).await? {
+            Some((_, section)) => group_skills(&mut *conn, &section.skills).await?,
+            None => Vec::new(),
+        };
+
+        let mut education = Vec::new();
+        if let Some((_, section)) = model::EducationSection::get_one_for(&mut *conn, &key).await? {
+            for degree in section.degrees {
+                let courses = model::Course::get_all_for(&mut *conn, &degree).await?;
+                education.push(DegreeEntry {
+                    degree: model::Degree::get_one_by_id(&mut *conn, degree.db_id()?).await?,
+                    courses: courses.into_iter().map(|(_, c)| c.name).collect(),
+                });
+            }
+        }
+
+        let selected: Vec<_> = model::SelectedBullet::get_all_for(&mut *conn, &key)
+            .await?
+            .into_iter()
+            .map(|(_, selection)| selection)
+            .collect();
+        let mut experience = Vec::new();
+        if let Some((_, section)) = model::ExperienceSection::get_one_for(&mut *conn, &key).await? {
+            for job in section.jobs {
+                experience.push(JobEntry {
+                    bullets: bullets(&mut *conn, &Parent::Job(job), &selected).await?,
+                    job: model::Job::get_one_by_id(&mut *conn, job.db_id()?).await?,
+                });
+            }
+        }
+
+        let mut projects = Vec::new();
+        if let Some((_, section)) = model::ProjectSection::get_one_for(&mut *conn, &key).await? {
+            for project in section.projects {
+                projects.push(ProjectEntry {
+                    bullets: bullets(&mut *conn, &Parent::Project(project), &selected).await?,
+                    project: model::Project::get_one_by_id(&mut *conn, project.db_id()?).await?,
+                });
+            }
+        }
+
+        let certifications =
+            match model::CertificationSection::get_one_for(&mut *conn, &key).await? {
+                Some((_, section)) => resolve(&mut *conn, &section.certifications).await?,
+                None => Vec::new(),
+            };
+        let awards = match model::AwardSection::get_one_for(&mut *conn, &key).await? {
+            Some((_, section)) => resolve(&mut *conn, &section.awards).await?,
+            None => Vec::new(),
+        };
+        let publications = match model::PublicationSection::get_one_for(&mut *conn, &key).await? {
+            Some((_, section)) => resolve(&mut *conn, &section.publications).await?,
+            None => Vec::new(),
+        };
+        let volunteering = match model::VolunteerSection::get_one_for(&mut *conn, &key).await? {
+            Some((_, section)) => resolve(&mut *conn, &section.volunteering).await?,
+            None => Vec::new(),
+        };
+        let languages = match model::LanguageSection::get_one_for(&mut *conn, &key).await? {
+            Some((_, section)) => resolve(&mut *conn, &section.languages).await?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            name,
+            contacts,
+            summary,
+            skills,
+            education,
+            experience,
+            projects,
+            certifications,
+            awards,
+            publications,
+            volunteering,
+            languages,
+        })
+    }
+}
+
+/// Group the given skills by the category each belongs to.
+///
+/// Groups are ordered by where their first skill appears in the given list & skills keep their
+/// order within a group. Uncategorized skills always come last.
+async fn group_skills(
+    conn: &mut SqliteConnection,
+    keys: &[Key],
+) -> anyhow::Result<Vec<SkillGroup>> {
+    let mut groups: Vec<(Option<Key>, SkillGroup)> = Vec::new();
+
+    for key in keys {
+        let skill = model::Skill::get_one_by_id(&mut *conn, key.db_id()?).await?;
+        match groups.iter_mut().find(|(c, _)| *c == skill.category) {
+            Some((_, group)) => group.skills.push(skill.name),
+            None => {
+                let category = match skill.category {
+                    Some(category) => {
+                        model::SkillCategory::path(&mut *conn, category.db_id()?).await?
+                    }
+                    None => Vec::new(),
+                };
+                groups.push((
+                    skill.category,
+                    SkillGroup {
+                        category,
+                        skills: vec![skill.name],
+                    },
+                ));
+            }
+        }
+    }
+    // stable, so categorized groups keep their relative order
+    groups.sort_by_key(|(category, _)| category.is_none());
+
+    Ok(groups.into_iter().map(|(_, group)| group).collect())
+}
+
+/// Get the value stored under each of the given keys, in order.
+async fn resolve<T: DbModel>(conn: &mut SqliteConnection, keys: &[Key]) -> anyhow::Result<Vec<T>> {
+    let mut values = Vec::with_capacity(keys.len());
+    for key in keys {
+        values.push(T::get_one_by_id(&mut *conn, key.db_id()?).await?);
+    }
+
+    Ok(values)
+}
+
+/// Get the text of each bullet the resume shows for the given job or project, in display order.
+///
+/// Uses the resume's own selection, order, & wording of bullets if it selected any for the parent,
+/// otherwise every bullet describing it, as is.
+async fn bullets(
+    conn: &mut SqliteConnection,
+    parent: &Parent,
+    selected: &[model::SelectedBullet],
+) -> anyhow::Result<Vec<String>> {
+    let items = model::DescriptionItem::get_all_for(conn, parent).await?;
+    let chosen: Vec<_> = selected
+        .iter()
+        .filter_map(|selection| {
+            items
+                .iter()
+                .find(|(id, _)| selection.item == Key::Db(*id))
+                .map(|(_, item)| selection.text_for(item).to_string())
+        })
+        .collect();
+
+    match chosen.is_empty() {
+        true => Ok(items.into_iter().map(|(_, item)| item.text).collect()),
+        false => Ok(chosen),
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn skills_are_grouped_by_category() {
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    let languages = model::SkillCategory::new("Languages".into(), None)
+        .create(&mut conn)
+        .await
+        .unwrap();
+    let systems = model::SkillCategory::new("Systems".into(), Some(Key::Db(languages)))
+        .create(&mut conn)
+        .await
+        .unwrap();
+    let mut skills = Vec::new();
+    for (name, category) in [
+        ("Bash", None),
+        ("Rust", Some(systems)),
+        ("Python", Some(languages)),
+        ("C", Some(systems)),
+    ] {
+        let id = model::Skill::new(name.into(), category.map(Key::Db))
+            .create(&mut conn)
+            .await
+            .unwrap();
+        skills.push(Key::Db(id));
+    }
+    let resume = model::Resume::new("Mine".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    model::SkillsSection::new(Key::Db(resume), skills)
+        .create(&mut conn)
+        .await
+        .unwrap();
+
+    let document = Document::load(&mut conn, resume).await.unwrap();
+
+    assert_eq!(
+        document.skills,
+        vec![
+            SkillGroup {
+                category: vec!["Languages".into(), "Systems".into()],
+                skills: vec!["Rust".into(), "C".into()],
+            },
+            SkillGroup {
+                category: vec!["Languages".into()],
+                skills: vec!["Python".into()],
+            },
+            SkillGroup {
+                category: Vec::new(),
+                skills: vec!["Bash".into()],
+            },
+        ]
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn extra_sections_keep_their_order() {
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    let mut languages = Vec::new();
+    for (name, proficiency) in [
+        ("Spanish", model::Proficiency::Professional),
+        ("English", model::Proficiency::Native),
+    ] {
+        let language = model::Language::new(name.into(), proficiency);
+        let id = language.create(&mut conn).await.unwrap();
+        languages.push((Key::Db(id), language));
+    }
+    let resume = model::Resume::new("Mine".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    model::LanguageSection::new(
+        Key::Db(resume),
+        languages.iter().map(|(key, _)| *key).collect(),
+    )
+    .create(&mut conn)
+    .await
+    .unwrap();
+
+    let document = Document::load(&mut conn, resume).await.unwrap();
+
+    assert_eq!(
+        document.languages,
+        languages.into_iter().map(|(_, l)| l).collect::<Vec<_>>()
+    );
+    assert!(document.certifications.is_empty());
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn selected_bullets_replace_a_jobs_own() {
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    let job = Key::Db(
+        model::Job::new(
+            "Acme".into(),
+            "Engineer".into(),
+            "2020-01-01".parse().unwrap(),
+            None,
+            Vec::new(),
+        )
+        .create(&mut conn)
+        .await
+        .unwrap(),
+    );
+    let mut items = Vec::new();
+    for (position, text) in ["Shipped", "Mentored", "Migrated"].into_iter().enumerate() {
+        let item = model::DescriptionItem::new(Parent::Job(job), position as i64, text.into());
+        items.push(Key::Db(item.create(&mut conn).await.unwrap()));
+    }
+    let resume = Key::Db(
+        model::Resume::new("Mine".into())
+            .create(&mut conn)
+            .await
+            .unwrap(),
+    );
+    model::ExperienceSection::new(resume, vec![job])
+        .create(&mut conn)
+        .await
+        .unwrap();
+    let mut reworded = model::SelectedBullet::new(resume, items[2], 0);
+    reworded.text = Some("Led a migration".into());
+    reworded.create(&mut conn).await.unwrap();
+    model::SelectedBullet::new(resume, items[0], 1)
+        .create(&mut conn)
+        .await
+        .unwrap();
+
+    let document = Document::load(&mut conn, resume.db_id().unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        document.experience[0].bullets,
+        vec!["Led a migration".to_string(), "Shipped".to_string()]
+    );
+}
@@ -0,0 +1,229 @@
+use anyhow::{bail, Context};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use sha2::{Digest, Sha256};
+use sqlx::SqliteConnection;
+
+use super::Format;
+
+/// A resume as it was exported, numbered in the order it's resume was exported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Version {
+    pub resume: i64,
+    pub number: i64,
+    pub exported_at: DateTime<Utc>,
+    pub format: Format,
+    /// sha256 of the content, hex encoded, to check it's exactly what was exported
+    pub hash: String,
+    /// The exported file, byte for byte
+    pub content: Vec<u8>,
+}
+
+type VersionRow = (i64, DateTime<Utc>, String, Vec<u8>, String);
+
+impl Version {
+    /// Record the given file as the next version of the resume it was exported from, unless it's
+    /// the same as the last version recorded, returning that instead.
+    pub async fn record(
+        conn: &mut SqliteConnection,
+        resume: i64,
+        format: Format,
+        content: Vec<u8>,
+    ) -> anyhow::Result<Self> {
+        let hash = hash(&content);
+        let latest: Option<VersionRow> = sqlx::query_as(
+            "SELECT version, exported_at, format, content, hash FROM ResumeVersion
+             WHERE resume_id=$1 ORDER BY version DESC LIMIT 1;",
+        )
+        .bind(resume)
+        .fetch_optional(&mut *conn)
+        .await?;
+        if let Some(latest) = latest {
+            let latest = Self::from_row(resume, latest)?;
+            if latest.hash == hash && latest.format == format {
+                return Ok(latest);
+            }
+        }
+
+        let exported_at = Utc::now();
+        let number = sqlx::query_scalar(
+            "INSERT INTO ResumeVersion (resume_id, version, exported_at, format, content, hash)
+             SELECT $1, COALESCE(MAX(version), 0) + 1, $2, $3, $4, $5
+             FROM ResumeVersion WHERE resume_id=$1
+             RETURNING version;",
+        )
+        .bind(resume)
+        .bind(exported_at)
+        .bind(format_name(format))
+        .bind(&content)
+        .bind(&hash)
+        .fetch_one(conn)
+        .await?;
+
+        Ok(Self {
+            resume,
+            number,
+            exported_at,
+            format,
+            hash,
+            content,
+        })
+    }
+
+    /// Get a version of a resume by it's number.
+    pub async fn get(
+        conn: &mut SqliteConnection,
+        resume: i64,
+        number: i64,
+    ) -> anyhow::Result<Self> {
+        let row: Option<VersionRow> = sqlx::query_as(
+            "SELECT version, exported_at, format, content, hash FROM ResumeVersion
+             WHERE resume_id=$1 AND version=$2;",
+        )
+        .bind(resume)
+        .bind(number)
+        .fetch_optional(conn)
+        .await?;
+        let row = row.with_context(|| format!("Resume {resume} has no version {number}"))?;
+
+        Self::from_row(resume, row)
+    }
+
+    /// Every version of a resume, oldest first.
+    pub async fn all_for(conn: &mut SqliteConnection, resume: i64) -> anyhow::Result<Vec<Self>> {
+        let rows: Vec<VersionRow> = sqlx::query_as(
+            "SELECT version, exported_at, format, content, hash FROM ResumeVersion
+             WHERE resume_id=$1 ORDER BY version;",
+        )
+        .bind(resume)
+        .fetch_all(conn)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Self::from_row(resume, row))
+            .collect()
+    }
+
+    fn from_row(
+        resume: i64,
+        (number, exported_at, format, content, hash): VersionRow,
+    ) -> anyhow::Result<Self> {
+        if self::hash(&content) != hash {
+            bail!("Version {number} of resume {resume} doesn't match it's hash, it's been altered");
+        }
+
+        Ok(Self {
+            resume,
+            number,
+            exported_at,
+            format: Format::from_str(&format, false).map_err(anyhow::Error::msg)?,
+            hash,
+            content,
+        })
+    }
+}
+
+/// The name a format is given on the command line, & stored under.
+fn format_name(format: Format) -> String {
+    format
+        .to_possible_value()
+        .expect("formats are never skipped")
+        .get_name()
+        .to_string()
+}
+
+fn hash(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn versions_keep_what_was_exported() {
+    use crate::model::{self, DbModel};
+
+    async fn export(conn: &mut SqliteConnection, resume: i64) -> Version {
+        let document = super::Document::load(conn, resume).await.unwrap();
+        let mut content = Vec::new();
+        Format::Markdown.write(&document, &mut content).unwrap();
+        Version::record(conn, resume, Format::Markdown, content)
+            .await
+            .unwrap()
+    }
+
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    let resume = model::Resume::new("Mine".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    let first = export(&mut conn, resume).await;
+    // nothing changed, so there's nothing new to record
+    assert_eq!(export(&mut conn, resume).await, first);
+    model::Resume::new("Renamed".into())
+        .update(&mut conn, resume)
+        .await
+        .unwrap();
+    let second = export(&mut conn, resume).await;
+
+    assert_eq!(Version::get(&mut conn, resume, 1).await.unwrap(), first);
+    assert_eq!(first.content, b"# Mine\n");
+    assert_eq!(second.number, 2);
+    assert_ne!(first.hash, second.hash);
+    assert_eq!(
+        Version::all_for(&mut conn, resume).await.unwrap(),
+        vec![first, second]
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn versions_arent_shared_with_resumes_added_later() {
+    use crate::model::{self, DbModel};
+
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    let deleted = model::Resume::new("Jane Doe".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    Version::record(
+        &mut conn,
+        deleted,
+        Format::Markdown,
+        b"# Jane Doe\n".to_vec(),
+    )
+    .await
+    .unwrap();
+    model::Resume::delete(&mut conn, deleted).await.unwrap();
+    let added = model::Resume::new("Frontend".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+
+    assert!(Version::all_for(&mut conn, added).await.unwrap().is_empty());
+    assert_eq!(Version::all_for(&mut conn, deleted).await.unwrap().len(), 1);
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn versions_cant_be_changed() {
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    Version::record(&mut conn, 1, Format::Markdown, b"# Mine\n".to_vec())
+        .await
+        .unwrap();
+
+    let update = sqlx::query("UPDATE ResumeVersion SET content=x'';")
+        .execute(&mut *conn)
+        .await;
+    let delete = sqlx::query("DELETE FROM ResumeVersion;")
+        .execute(&mut *conn)
+        .await;
+
+    assert!(update.is_err());
+    assert!(delete.is_err());
+    assert!(Version::get(&mut conn, 1, 1).await.is_ok());
+}
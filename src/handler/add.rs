@@ -1,11 +1,12 @@
-use std::io::Write;
-
+use chrono::NaiveDate;
 use clap::{Args, Subcommand};
 
-use crate::model;
-use crate::state::{AddNew, Key};
+use crate::model::{self, DbModel, Parent};
+use crate::state::{AddNew, Key, Replace};
 use crate::store::Store;
 
+use super::Outcome;
+
 #[derive(Debug, Args)]
 pub struct Add {
     #[command(subcommand)]
@@ -14,25 +15,500 @@ pub struct Add {
 
 #[derive(Debug, Subcommand)]
 enum Command {
-    Resume { name: String },
-    // Skill { name: String },
+    Resume {
+        name: String,
+    },
+    Header {
+        /// id of the resume the header is for
+        #[arg(short, long)]
+        resume: i64,
+        /// name to display at the top of the resume
+        name: String,
+    },
+    Summary {
+        /// id of the resume the summary is for
+        #[arg(short, long)]
+        resume: i64,
+        content: String,
+    },
+    Section {
+        kind: super::SectionKind,
+        /// id of the resume the section is for
+        #[arg(short, long)]
+        resume: i64,
+        /// items to list in the section, in display order; names for skills, ids for everything
+        /// else
+        #[arg(required = true)]
+        items: Vec<String>,
+    },
+    /// choose a job or project bullet to show on a resume
+    SelectedBullet {
+        /// id of the resume to show the bullet on
+        #[arg(short, long)]
+        resume: i64,
+        /// id of the bullet to show
+        bullet: i64,
+        /// text to show on this resume instead of the bullet's own text
+        #[arg(short, long)]
+        text: Option<String>,
+        /// label of the bullet's variant to show on this resume instead of its own text
+        #[arg(short, long, conflicts_with = "text")]
+        variant: Option<String>,
+    },
+    /// add an alternative phrasing to a job or project bullet
+    Variant {
+        /// id of the bullet the variant rephrases
+        #[arg(short, long)]
+        bullet: i64,
+        /// name for the variant, e.g. short or technical; must be unique to the bullet
+        #[arg(short, long)]
+        label: String,
+        text: String,
+    },
+    Contact {
+        /// id of the resume header to display the contact method in
+        #[arg(long)]
+        header: i64,
+        #[command(subcommand)]
+        method: super::ContactCommand,
+    },
+    Job {
+        /// who the job was for
+        #[arg(short, long)]
+        employer: String,
+        /// the job's title
+        #[arg(short, long)]
+        title: String,
+        /// first day on the job, as YYYY-MM-DD
+        #[arg(short, long)]
+        start: NaiveDate,
+        /// last day on the job, as YYYY-MM-DD; leave off if this is a current job
+        #[arg(long)]
+        end: Option<NaiveDate>,
+        /// a bullet point describing the job, may be given multiple times & keeps the given order
+        #[arg(short, long = "bullet")]
+        bullets: Vec<String>,
+        /// name of a skill practiced on the job, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Education {
+        /// level of the degree, e.g. Associate's, Bachelor's, Master's, etc.
+        #[arg(short, long)]
+        level: String,
+        #[arg(short, long)]
+        major: String,
+        #[arg(long)]
+        minor: Option<String>,
+        #[arg(short, long, value_parser = model::Degree::parse_gpa)]
+        gpa: Option<f64>,
+        #[arg(short, long)]
+        description: Option<String>,
+        /// name of a course studied for the degree, may be given multiple times
+        #[arg(short, long = "course")]
+        courses: Vec<String>,
+        /// name of a skill developed over the whole degree, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Course {
+        /// id of the degree the course was studied for
+        #[arg(short, long)]
+        degree: i64,
+        name: String,
+        /// name of a skill developed in the course, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Project {
+        name: String,
+        /// http or https url where the project's source can be found
+        #[arg(short, long, value_parser = model::ContactMethod::parse_href)]
+        repository: Option<url::Url>,
+        /// http or https url where the project can be seen running
+        #[arg(short, long, value_parser = model::ContactMethod::parse_href)]
+        demo: Option<url::Url>,
+        /// when work on the project began, as YYYY-MM-DD
+        #[arg(short, long)]
+        start: Option<NaiveDate>,
+        /// when work on the project ended, as YYYY-MM-DD; leave off if still ongoing
+        #[arg(long)]
+        end: Option<NaiveDate>,
+        /// a bullet point describing the project, may be given multiple times & keeps the given
+        /// order
+        #[arg(short, long = "bullet")]
+        bullets: Vec<String>,
+        /// name of a skill practiced on the project, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Certification {
+        name: String,
+        /// who granted the certification
+        #[arg(short, long)]
+        issuer: String,
+        /// when the certification was granted, as YYYY-MM-DD
+        #[arg(short = 'd', long)]
+        issued: NaiveDate,
+        /// when the certification lapses, as YYYY-MM-DD; leave off if it never does
+        #[arg(short, long)]
+        expires: Option<NaiveDate>,
+        /// id the credential can be verified with
+        #[arg(short, long)]
+        credential_id: Option<String>,
+    },
+    Award {
+        name: String,
+        /// who gave the award
+        #[arg(short, long)]
+        issuer: Option<String>,
+        /// when the award was given, as YYYY-MM-DD
+        #[arg(short, long)]
+        awarded: Option<NaiveDate>,
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    Publication {
+        title: String,
+        /// journal, conference, blog, etc. the work was published in
+        #[arg(short, long)]
+        publisher: Option<String>,
+        /// when the work was published, as YYYY-MM-DD
+        #[arg(short = 'd', long)]
+        published: Option<NaiveDate>,
+        /// http or https url where the work can be read
+        #[arg(short, long, value_parser = model::ContactMethod::parse_href)]
+        url: Option<url::Url>,
+    },
+    Volunteer {
+        /// who the work was done for
+        #[arg(short, long)]
+        organization: String,
+        /// role filled while volunteering
+        #[arg(short, long)]
+        role: String,
+        /// first day volunteering, as YYYY-MM-DD
+        #[arg(short, long)]
+        start: NaiveDate,
+        /// last day volunteering, as YYYY-MM-DD; leave off if still ongoing
+        #[arg(long)]
+        end: Option<NaiveDate>,
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    Language {
+        name: String,
+        /// how well the language is spoken
+        #[arg(short, long, value_enum)]
+        proficiency: model::Proficiency,
+    },
+    Skill {
+        name: String,
+        /// name of the category to group the skill under
+        #[arg(short, long)]
+        category: Option<String>,
+        /// another name the skill goes by, may be given multiple times
+        #[arg(short, long = "alias")]
+        aliases: Vec<String>,
+    },
+    Category {
+        name: String,
+        /// name of the category to nest this one under, leave off for a top level category
+        #[arg(short, long)]
+        parent: Option<String>,
+    },
 }
 
 impl Add {
-    pub async fn handle(self, store: &mut Store, writer: &mut impl Write) -> anyhow::Result<Key> {
+    pub async fn handle(self, store: &mut Store) -> anyhow::Result<Outcome> {
         match self.cmd {
             Command::Resume { name } => {
-                let resume = model::Resume::new(name);
-                Ok(store.add_new(resume))
-            } // Command::Skill { name } => {
-              //     let model::Skill {
-              //         name: new_name,
-              //         id: new_id,
-              //     } = model::Skill::add_new(pool, name).await?;
-              //     writeln!(writer, "New Skill, {new_name}, created with id {new_id}")?;
-
-              //     Ok(new_id)
-              // }
+                let resume = model::Resume::new(name.clone());
+                let key = store.add_new(resume);
+                Ok(Outcome::new::<model::Resume>(key, move |key| {
+                    format!("New Resume, {name}, created with id {key}")
+                }))
+            }
+            Command::Header { resume, name } => {
+                let header = model::HeaderSection::new(Key::Db(resume), name.clone());
+                let key = store.add_new(header);
+                Ok(Outcome::new::<model::HeaderSection>(key, move |key| {
+                    format!("New Header, {name}, created with id {key}")
+                }))
+            }
+            Command::Summary { resume, content } => {
+                let summary = model::SummarySection::new(Key::Db(resume), content);
+                let key = store.add_new(summary);
+                Ok(Outcome::new::<model::SummarySection>(key, move |key| {
+                    format!("New Summary created with id {key}")
+                }))
+            }
+            Command::Section {
+                kind,
+                resume,
+                items,
+            } => {
+                let items = kind.keys(store, &items).await?;
+                let key = kind.add(store, Key::Db(resume), items);
+                Ok(kind.outcome(key, "created"))
+            }
+            Command::SelectedBullet {
+                resume,
+                bullet,
+                text,
+                variant,
+            } => {
+                let mut conn = store.pool().acquire().await?;
+                let resume = Key::Db(resume);
+                // make sure the bullet exists before selecting it
+                let item = model::DescriptionItem::get_one_by_id(&mut conn, bullet).await?;
+                let variant = variant
+                    .map(|label| super::variant_label(&item, bullet, &label))
+                    .transpose()?;
+                let selected = model::SelectedBullet::get_all_for(&mut conn, &resume).await?;
+                if selected.iter().any(|(_, s)| s.item == Key::Db(bullet)) {
+                    anyhow::bail!("Bullet {bullet} is already selected for this resume");
+                }
+                // new selections go after any already made
+                let position = selected.last().map_or(0, |(_, s)| s.position + 1);
+                let mut selection = model::SelectedBullet::new(resume, Key::Db(bullet), position);
+                selection.text = text;
+                selection.variant = variant;
+                let key = store.add_new(selection);
+                Ok(Outcome::new::<model::SelectedBullet>(key, move |key| {
+                    format!("Bullet {bullet} selected with id {key}")
+                }))
+            }
+            Command::Variant {
+                bullet,
+                label,
+                text,
+            } => {
+                let mut item: model::DescriptionItem = super::saved(store, bullet).await?;
+                if item.variant(&label).is_some() {
+                    anyhow::bail!("Bullet {bullet} already has a variant labelled {label}");
+                }
+                item.variants.push(model::Variant::new(label.clone(), text));
+                let key = store.replace(Key::Db(bullet), item);
+                Ok(Outcome::new::<model::DescriptionItem>(key, move |key| {
+                    format!("New variant, {label}, added to bullet {key}")
+                }))
+            }
+            Command::Contact { header, method } => {
+                let method = method.method();
+                let contact = model::Contact::new(Key::Db(header), method.clone());
+                let key = store.add_new(contact);
+                Ok(Outcome::new::<model::Contact>(key, move |key| {
+                    format!("New Contact, {method}, created with id {key}")
+                }))
+            }
+            Command::Job {
+                employer,
+                title,
+                start,
+                end,
+                bullets,
+                skills,
+            } => {
+                let skills = super::skill_keys(store, &skills).await?;
+                let job = model::Job::new(employer.clone(), title.clone(), start, end, skills);
+                let key = store.add_new(job);
+                for (position, text) in bullets.into_iter().enumerate() {
+                    store.add_new(model::DescriptionItem::new(
+                        Parent::Job(key),
+                        position as i64,
+                        text,
+                    ));
+                }
+                Ok(Outcome::new::<model::Job>(key, move |key| {
+                    format!("New Job, {title} at {employer}, created with id {key}")
+                }))
+            }
+            Command::Education {
+                level,
+                major,
+                minor,
+                gpa,
+                description,
+                courses,
+                skills,
+            } => {
+                let degree = model::Degree {
+                    level: level.clone(),
+                    major: major.clone(),
+                    minor,
+                    gpa,
+                    description,
+                    skills: super::skill_keys(store, &skills).await?,
+                };
+                let key = store.add_new(degree);
+                for name in courses {
+                    store.add_new(model::Course::new(key, name, Vec::new()));
+                }
+                Ok(Outcome::new::<model::Degree>(key, move |key| {
+                    format!("New Degree, {level} in {major}, created with id {key}")
+                }))
+            }
+            Command::Course {
+                degree,
+                name,
+                skills,
+            } => {
+                let skills = super::skill_keys(store, &skills).await?;
+                let course = model::Course::new(Key::Db(degree), name.clone(), skills);
+                let key = store.add_new(course);
+                Ok(Outcome::new::<model::Course>(key, move |key| {
+                    format!("New Course, {name}, created with id {key}")
+                }))
+            }
+            Command::Project {
+                name,
+                repository,
+                demo,
+                start,
+                end,
+                bullets,
+                skills,
+            } => {
+                let project = model::Project {
+                    name: name.clone(),
+                    repository,
+                    demo,
+                    start,
+                    end,
+                    skills: super::skill_keys(store, &skills).await?,
+                };
+                let key = store.add_new(project);
+                for (position, text) in bullets.into_iter().enumerate() {
+                    store.add_new(model::DescriptionItem::new(
+                        Parent::Project(key),
+                        position as i64,
+                        text,
+                    ));
+                }
+                Ok(Outcome::new::<model::Project>(key, move |key| {
+                    format!("New Project, {name}, created with id {key}")
+                }))
+            }
+            Command::Certification {
+                name,
+                issuer,
+                issued,
+                expires,
+                credential_id,
+            } => {
+                let certification = model::Certification {
+                    name: name.clone(),
+                    issuer: issuer.clone(),
+                    issued,
+                    expires,
+                    credential_id,
+                };
+                let key = store.add_new(certification);
+                Ok(Outcome::new::<model::Certification>(key, move |key| {
+                    format!("New Certification, {name} from {issuer}, created with id {key}")
+                }))
+            }
+            Command::Award {
+                name,
+                issuer,
+                awarded,
+                description,
+            } => {
+                let award = model::Award {
+                    name: name.clone(),
+                    issuer,
+                    awarded,
+                    description,
+                };
+                let key = store.add_new(award);
+                Ok(Outcome::new::<model::Award>(key, move |key| {
+                    format!("New Award, {name}, created with id {key}")
+                }))
+            }
+            Command::Publication {
+                title,
+                publisher,
+                published,
+                url,
+            } => {
+                let publication = model::Publication {
+                    title: title.clone(),
+                    publisher,
+                    published,
+                    url,
+                };
+                let key = store.add_new(publication);
+                Ok(Outcome::new::<model::Publication>(key, move |key| {
+                    format!("New Publication, {title}, created with id {key}")
+                }))
+            }
+            Command::Volunteer {
+                organization,
+                role,
+                start,
+                end,
+                description,
+            } => {
+                let volunteer = model::Volunteer {
+                    organization: organization.clone(),
+                    role: role.clone(),
+                    start,
+                    end,
+                    description,
+                };
+                let key = store.add_new(volunteer);
+                Ok(Outcome::new::<model::Volunteer>(key, move |key| {
+                    format!("New Volunteer work, {role} at {organization}, created with id {key}")
+                }))
+            }
+            Command::Language { name, proficiency } => {
+                let key = store.add_new(model::Language::new(name.clone(), proficiency));
+                Ok(Outcome::new::<model::Language>(key, move |key| {
+                    format!("New Language, {name}, created with id {key}")
+                }))
+            }
+            Command::Skill {
+                name,
+                category,
+                aliases,
+            } => {
+                let mut skill = model::Skill::new(name.clone(), None);
+                for alias in aliases {
+                    if !skill.is_called(&alias) {
+                        skill.aliases.push(alias);
+                    }
+                }
+                let names: Vec<_> = std::iter::once(&skill.name)
+                    .chain(&skill.aliases)
+                    .map(String::as_str)
+                    .collect();
+                super::check_skill_names(store, &names, None).await?;
+                if let Some(category) = category {
+                    skill.category = Some(super::category_key(store, &category).await?);
+                }
+                let key = store.add_new(skill);
+                Ok(Outcome::new::<model::Skill>(key, move |key| {
+                    format!("New Skill, {name}, created with id {key}")
+                }))
+            }
+            Command::Category { name, parent } => {
+                let mut conn = store.pool().acquire().await?;
+                if let Some((id, _)) =
+                    model::SkillCategory::get_one_by_name(&mut conn, &name).await?
+                {
+                    anyhow::bail!("Skill category {name} already exists with id {id}");
+                }
+                drop(conn);
+                let parent = match parent {
+                    Some(parent) => Some(super::category_key(store, &parent).await?),
+                    None => None,
+                };
+                let key = store.add_new(model::SkillCategory::new(name.clone(), parent));
+                Ok(Outcome::new::<model::SkillCategory>(key, move |key| {
+                    format!("New Skill Category, {name}, created with id {key}")
+                }))
+            }
         }
     }
 }
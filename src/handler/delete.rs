@@ -0,0 +1,198 @@
+use std::fmt::Debug;
+
+use clap::{Args, Subcommand};
+
+use crate::model::{self, DbModel};
+use crate::state::{AppState, Get, Key, Remove, Replace};
+use crate::store::Store;
+
+use super::Outcome;
+
+/// Delete something, along with everything that can't exist without it
+#[derive(Debug, Args)]
+pub struct Delete {
+    #[command(subcommand)]
+    cmd: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// delete a resume, along with it's header, sections & bullet selections
+    Resume {
+        /// id of the resume to delete
+        id: i64,
+    },
+    /// delete a resume header, along with it's contact methods
+    Header {
+        /// id of the header to delete
+        id: i64,
+    },
+    Contact {
+        /// id of the contact method to delete
+        id: i64,
+    },
+    Summary {
+        /// id of the resume the summary is for
+        #[arg(short, long)]
+        resume: i64,
+    },
+    Section {
+        kind: super::SectionKind,
+        /// id of the resume the section is for
+        #[arg(short, long)]
+        resume: i64,
+    },
+    /// stop showing a bullet on a resume
+    SelectedBullet {
+        /// id of the resume the bullet is shown on
+        #[arg(short, long)]
+        resume: i64,
+        /// id of the selected bullet
+        bullet: i64,
+    },
+    /// delete a job or project bullet, removing it from any resume showing it
+    Bullet {
+        /// id of the bullet to delete
+        id: i64,
+    },
+    /// delete one of a bullet's variants
+    Variant {
+        /// id of the bullet the variant rephrases
+        #[arg(short, long)]
+        bullet: i64,
+        /// label of the variant to delete
+        label: String,
+    },
+    /// delete a job, along with it's bullets
+    Job {
+        /// id of the job to delete
+        id: i64,
+    },
+    /// delete a degree, along with it's courses
+    Education {
+        /// id of the degree to delete
+        id: i64,
+    },
+    Course {
+        /// id of the course to delete
+        id: i64,
+    },
+    /// delete a project, along with it's bullets
+    Project {
+        /// id of the project to delete
+        id: i64,
+    },
+    Certification {
+        /// id of the certification to delete
+        id: i64,
+    },
+    Award {
+        /// id of the award to delete
+        id: i64,
+    },
+    Publication {
+        /// id of the publication to delete
+        id: i64,
+    },
+    Volunteer {
+        /// id of the volunteer work to delete
+        id: i64,
+    },
+    Language {
+        /// id of the language to delete
+        id: i64,
+    },
+    /// delete a skill, removing it from everything it was practiced in
+    Skill {
+        /// id of the skill to delete
+        id: i64,
+    },
+    /// delete a skill category & any nested under it, leaving their skills uncategorized
+    Category {
+        /// id of the skill category to delete
+        id: i64,
+    },
+}
+
+impl Delete {
+    pub async fn handle(self, store: &mut Store) -> anyhow::Result<Outcome> {
+        let outcome = match self.cmd {
+            Command::Resume { id } => delete::<model::Resume>(store, id, "Resume").await?,
+            Command::Header { id } => delete::<model::HeaderSection>(store, id, "Header").await?,
+            Command::Contact { id } => delete::<model::Contact>(store, id, "Contact").await?,
+            Command::Summary { resume } => {
+                let mut conn = store.pool().acquire().await?;
+                let (id, _) = model::SummarySection::get_one_for(&mut conn, &Key::Db(resume))
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("The resume has no summary"))?;
+                drop(conn);
+
+                delete::<model::SummarySection>(store, id, "Summary").await?
+            }
+            Command::Section { kind, resume } => {
+                let key = kind.delete(store, Key::Db(resume)).await?;
+
+                kind.outcome(key, "deleted")
+            }
+            Command::SelectedBullet { resume, bullet } => {
+                let mut conn = store.pool().acquire().await?;
+                let (id, _) = model::SelectedBullet::get_all_for(&mut conn, &Key::Db(resume))
+                    .await?
+                    .into_iter()
+                    .find(|(_, s)| s.item == Key::Db(bullet))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Bullet {bullet} isn't selected for this resume")
+                    })?;
+                drop(conn);
+
+                delete::<model::SelectedBullet>(store, id, "Selected bullet").await?
+            }
+            Command::Bullet { id } => delete::<model::DescriptionItem>(store, id, "Bullet").await?,
+            Command::Variant { bullet, label } => {
+                let mut item: model::DescriptionItem = super::saved(store, bullet).await?;
+                let label = super::variant_label(&item, bullet, &label)?;
+                item.variants.retain(|variant| variant.label != label);
+
+                let key = store.replace(Key::Db(bullet), item);
+
+                Outcome::new::<model::DescriptionItem>(key, move |key| {
+                    format!("Variant {label} of bullet {key} deleted")
+                })
+            }
+            Command::Job { id } => delete::<model::Job>(store, id, "Job").await?,
+            Command::Education { id } => delete::<model::Degree>(store, id, "Degree").await?,
+            Command::Course { id } => delete::<model::Course>(store, id, "Course").await?,
+            Command::Project { id } => delete::<model::Project>(store, id, "Project").await?,
+            Command::Certification { id } => {
+                delete::<model::Certification>(store, id, "Certification").await?
+            }
+            Command::Award { id } => delete::<model::Award>(store, id, "Award").await?,
+            Command::Publication { id } => {
+                delete::<model::Publication>(store, id, "Publication").await?
+            }
+            Command::Volunteer { id } => delete::<model::Volunteer>(store, id, "Volunteer").await?,
+            Command::Language { id } => delete::<model::Language>(store, id, "Language").await?,
+            Command::Skill { id } => delete::<model::Skill>(store, id, "Skill").await?,
+            Command::Category { id } => {
+                delete::<model::SkillCategory>(store, id, "Skill Category").await?
+            }
+        };
+
+        Ok(outcome)
+    }
+}
+
+/// Delete the saved value with the given id, failing if there's none.
+async fn delete<M>(store: &mut Store, id: i64, name: &'static str) -> anyhow::Result<Outcome>
+where
+    M: DbModel + Clone + Debug + 'static,
+    AppState: Get<M, Key>,
+    Store: Remove<M, Key>,
+{
+    super::saved::<M>(store, id).await?;
+    let key = Remove::<M, Key>::remove(store, Key::Db(id));
+
+    Ok(Outcome::new::<M>(key, move |key| {
+        format!("{name} {key} deleted")
+    }))
+}
@@ -0,0 +1,835 @@
+use chrono::NaiveDate;
+use clap::{Args, Subcommand};
+
+use crate::model::{self, Parent};
+use crate::state::{AddNew, Key, Replace};
+use crate::store::Store;
+
+use super::Outcome;
+
+#[derive(Debug, Args)]
+pub struct Edit {
+    #[command(subcommand)]
+    cmd: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    Resume {
+        /// id of the resume to edit
+        id: i64,
+        /// new name for the resume
+        name: String,
+    },
+    Header {
+        /// id of the header to edit
+        id: i64,
+        /// new name to display at the top of the resume
+        name: String,
+    },
+    Contact {
+        /// id of the contact method to edit
+        id: i64,
+        /// the contact method to display instead
+        #[command(subcommand)]
+        method: super::ContactCommand,
+    },
+    Summary {
+        /// id of the resume the summary is for
+        #[arg(short, long)]
+        resume: i64,
+        /// new summary content
+        content: String,
+    },
+    /// change which items a resume section lists & their order
+    Section {
+        kind: super::SectionKind,
+        /// id of the resume the section is for
+        #[arg(short, long)]
+        resume: i64,
+        /// every item to list in the section, in display order; names for skills, ids for
+        /// everything else
+        #[arg(required = true)]
+        items: Vec<String>,
+    },
+    /// change how a bullet selected for a resume is shown
+    SelectedBullet {
+        /// id of the resume the bullet is shown on
+        #[arg(short, long)]
+        resume: i64,
+        /// id of the selected bullet
+        bullet: i64,
+        /// change the text shown on this resume instead of the bullet's own text
+        #[arg(short, long)]
+        text: Option<String>,
+        /// change the label of the bullet's variant to show on this resume
+        #[arg(short, long, conflicts_with = "text")]
+        variant: Option<String>,
+        /// show the bullet's own text on this resume again
+        #[arg(long, conflicts_with_all = ["text", "variant"])]
+        original: bool,
+        /// move the bullet to the given position amongst those selected for the resume, counting
+        /// from 0, shifting the others to make room
+        #[arg(short, long)]
+        position: Option<usize>,
+    },
+    /// reword or move a job or project bullet
+    Bullet {
+        /// id of the bullet to edit
+        id: i64,
+        /// new text for the bullet
+        text: Option<String>,
+        /// move the bullet to the given position amongst it's job or project's bullets, counting
+        /// from 0, shifting the others to make room
+        #[arg(short, long)]
+        position: Option<usize>,
+    },
+    /// change the text of one of a bullet's variants
+    Variant {
+        /// id of the bullet the variant rephrases
+        #[arg(short, long)]
+        bullet: i64,
+        /// label of the variant to edit
+        label: String,
+        /// new text for the variant
+        text: String,
+    },
+    Job {
+        /// id of the job to edit
+        id: i64,
+        /// change who the job was for
+        #[arg(short, long)]
+        employer: Option<String>,
+        /// change the job's title
+        #[arg(short, long)]
+        title: Option<String>,
+        /// change the first day on the job, as YYYY-MM-DD
+        #[arg(short, long)]
+        start: Option<NaiveDate>,
+        /// change the last day on the job, as YYYY-MM-DD
+        #[arg(long)]
+        end: Option<NaiveDate>,
+        /// mark the job as current by removing it's end date
+        #[arg(long, conflicts_with = "end")]
+        present: bool,
+        /// append a bullet point describing the job, may be given multiple times
+        #[arg(short, long = "bullet")]
+        bullets: Vec<String>,
+        /// name of an additional skill practiced on the job, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Education {
+        /// id of the degree to edit
+        id: i64,
+        /// change the level of the degree
+        #[arg(short, long)]
+        level: Option<String>,
+        /// change the degree's major
+        #[arg(short, long)]
+        major: Option<String>,
+        /// change the degree's minor
+        #[arg(long)]
+        minor: Option<String>,
+        /// remove the degree's minor
+        #[arg(long, conflicts_with = "minor")]
+        clear_minor: bool,
+        /// change the degree's GPA
+        #[arg(short, long, value_parser = model::Degree::parse_gpa)]
+        gpa: Option<f64>,
+        /// remove the degree's GPA
+        #[arg(long, conflicts_with = "gpa")]
+        clear_gpa: bool,
+        /// change the degree's description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// remove the degree's description
+        #[arg(long, conflicts_with = "description")]
+        clear_description: bool,
+        /// name of an additional course studied for the degree, may be given multiple times
+        #[arg(short, long = "course")]
+        courses: Vec<String>,
+        /// name of an additional skill developed over the degree, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Course {
+        /// id of the course to edit
+        id: i64,
+        /// change the course's name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// name of an additional skill developed in the course, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Project {
+        /// id of the project to edit
+        id: i64,
+        /// change the project's name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// change the http or https url where the project's source can be found
+        #[arg(short, long, value_parser = model::ContactMethod::parse_href)]
+        repository: Option<url::Url>,
+        /// change the http or https url where the project can be seen running
+        #[arg(short, long, value_parser = model::ContactMethod::parse_href)]
+        demo: Option<url::Url>,
+        /// change when work on the project began, as YYYY-MM-DD
+        #[arg(short, long)]
+        start: Option<NaiveDate>,
+        /// change when work on the project ended, as YYYY-MM-DD
+        #[arg(long)]
+        end: Option<NaiveDate>,
+        /// mark the project as ongoing by removing it's end date
+        #[arg(long, conflicts_with = "end")]
+        ongoing: bool,
+        /// append a bullet point describing the project, may be given multiple times
+        #[arg(short, long = "bullet")]
+        bullets: Vec<String>,
+        /// name of an additional skill practiced on the project, may be given multiple times
+        #[arg(long = "skill")]
+        skills: Vec<String>,
+    },
+    Certification {
+        /// id of the certification to edit
+        id: i64,
+        /// change the certification's name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// change who granted the certification
+        #[arg(short, long)]
+        issuer: Option<String>,
+        /// change when the certification was granted, as YYYY-MM-DD
+        #[arg(short = 'd', long)]
+        issued: Option<NaiveDate>,
+        /// change when the certification lapses, as YYYY-MM-DD
+        #[arg(short, long)]
+        expires: Option<NaiveDate>,
+        /// mark the certification as never lapsing by removing it's expiry date
+        #[arg(long, conflicts_with = "expires")]
+        no_expiry: bool,
+        /// change the id the credential can be verified with
+        #[arg(short, long)]
+        credential_id: Option<String>,
+    },
+    Award {
+        /// id of the award to edit
+        id: i64,
+        /// change the award's name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// change who gave the award
+        #[arg(short, long)]
+        issuer: Option<String>,
+        /// change when the award was given, as YYYY-MM-DD
+        #[arg(short, long)]
+        awarded: Option<NaiveDate>,
+        /// change the award's description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    Publication {
+        /// id of the publication to edit
+        id: i64,
+        /// change the publication's title
+        #[arg(short, long)]
+        title: Option<String>,
+        /// change the journal, conference, blog, etc. the work was published in
+        #[arg(short, long)]
+        publisher: Option<String>,
+        /// change when the work was published, as YYYY-MM-DD
+        #[arg(short = 'd', long)]
+        published: Option<NaiveDate>,
+        /// change the http or https url where the work can be read
+        #[arg(short, long, value_parser = model::ContactMethod::parse_href)]
+        url: Option<url::Url>,
+    },
+    Volunteer {
+        /// id of the volunteer work to edit
+        id: i64,
+        /// change who the work was done for
+        #[arg(short, long)]
+        organization: Option<String>,
+        /// change the role filled while volunteering
+        #[arg(short, long)]
+        role: Option<String>,
+        /// change the first day volunteering, as YYYY-MM-DD
+        #[arg(short, long)]
+        start: Option<NaiveDate>,
+        /// change the last day volunteering, as YYYY-MM-DD
+        #[arg(long)]
+        end: Option<NaiveDate>,
+        /// mark the work as ongoing by removing it's end date
+        #[arg(long, conflicts_with = "end")]
+        ongoing: bool,
+        /// change the work's description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    Language {
+        /// id of the language to edit
+        id: i64,
+        /// change the language's name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// change how well the language is spoken
+        #[arg(short, long, value_enum)]
+        proficiency: Option<model::Proficiency>,
+    },
+    Skill {
+        /// id of the skill to edit
+        id: i64,
+        /// change the skill's name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// change the name of the category the skill is grouped under
+        #[arg(short, long)]
+        category: Option<String>,
+        /// remove the skill from any category
+        #[arg(long, conflicts_with = "category")]
+        uncategorized: bool,
+        /// another name the skill goes by, may be given multiple times
+        #[arg(short, long = "alias")]
+        aliases: Vec<String>,
+        /// stop the skill going by the given alias, may be given multiple times
+        #[arg(long = "remove-alias")]
+        remove_aliases: Vec<String>,
+    },
+    Category {
+        /// id of the skill category to edit
+        id: i64,
+        /// change the category's name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// name of the category to nest this one under instead
+        #[arg(short, long)]
+        parent: Option<String>,
+        /// stop nesting this category under another, making it a top level category
+        #[arg(long, conflicts_with = "parent")]
+        top_level: bool,
+    },
+}
+
+impl Edit {
+    pub async fn handle(self, store: &mut Store) -> anyhow::Result<Outcome> {
+        match self.cmd {
+            Command::Resume { id, name } => {
+                let mut resume: model::Resume = super::saved(store, id).await?;
+                resume.name = name;
+
+                let key = store.replace(Key::Db(id), resume);
+                Ok(Outcome::new::<model::Resume>(key, move |key| {
+                    format!("Resume {key} updated")
+                }))
+            }
+            Command::Header { id, name } => {
+                let mut header: model::HeaderSection = super::saved(store, id).await?;
+                header.name = name;
+
+                let key = store.replace(Key::Db(id), header);
+                Ok(Outcome::new::<model::HeaderSection>(key, move |key| {
+                    format!("Header {key} updated")
+                }))
+            }
+            Command::Contact { id, method } => {
+                let mut contact: model::Contact = super::saved(store, id).await?;
+                contact.method = method.method();
+
+                let key = store.replace(Key::Db(id), contact);
+                Ok(Outcome::new::<model::Contact>(key, move |key| {
+                    format!("Contact {key} updated")
+                }))
+            }
+            Command::Summary { resume, content } => {
+                let mut conn = store.pool().acquire().await?;
+                let resume = Key::Db(resume);
+                let (id, _) = model::SummarySection::get_one_for(&mut conn, &resume)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("The resume has no summary, add one first"))?;
+                drop(conn);
+                // load the summary as it was, so saving can tell if anyone else changed it since
+                super::saved::<model::SummarySection>(store, id).await?;
+
+                let key = store.replace(Key::Db(id), model::SummarySection::new(resume, content));
+                Ok(Outcome::new::<model::SummarySection>(key, move |key| {
+                    format!("Summary {key} updated")
+                }))
+            }
+            Command::Section {
+                kind,
+                resume,
+                items,
+            } => {
+                let items = kind.keys(store, &items).await?;
+                let key = kind.replace(store, Key::Db(resume), items).await?;
+                Ok(kind.outcome(key, "updated"))
+            }
+            Command::SelectedBullet {
+                resume,
+                bullet,
+                text,
+                variant,
+                original,
+                position,
+            } => {
+                let mut conn = store.pool().acquire().await?;
+                let resume = Key::Db(resume);
+                let selected = model::SelectedBullet::get_all_for(&mut conn, &resume).await?;
+                let (id, _) = selected
+                    .iter()
+                    .find(|(_, s)| s.item == Key::Db(bullet))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Bullet {bullet} isn't selected for this resume")
+                    })?;
+                let id = *id;
+                drop(conn);
+                let mut selection: model::SelectedBullet = super::saved(store, id).await?;
+
+                if let Some(label) = variant {
+                    let item: model::DescriptionItem = super::saved(store, bullet).await?;
+                    selection.variant = Some(super::variant_label(&item, bullet, &label)?);
+                    selection.text = None;
+                }
+                if text.is_some() || original {
+                    selection.text = text;
+                }
+                if original {
+                    selection.variant = None;
+                }
+                if let Some(position) = position {
+                    let siblings = selected.into_iter().map(|(id, _)| id);
+                    super::reposition(store, siblings, (id, &mut selection), position, |s| {
+                        &mut s.position
+                    })
+                    .await?;
+                }
+
+                let key = store.replace(Key::Db(id), selection);
+                Ok(Outcome::new::<model::SelectedBullet>(key, move |key| {
+                    format!("Selected bullet {key} updated")
+                }))
+            }
+            Command::Bullet { id, text, position } => {
+                let mut item: model::DescriptionItem = super::saved(store, id).await?;
+
+                if let Some(text) = text {
+                    item.text = text;
+                }
+                if let Some(position) = position {
+                    let mut conn = store.pool().acquire().await?;
+                    let siblings = model::DescriptionItem::get_all_for(&mut conn, &item.parent)
+                        .await?
+                        .into_iter()
+                        .map(|(id, _)| id);
+                    drop(conn);
+                    super::reposition(store, siblings, (id, &mut item), position, |i| {
+                        &mut i.position
+                    })
+                    .await?;
+                }
+
+                let key = store.replace(Key::Db(id), item);
+                Ok(Outcome::new::<model::DescriptionItem>(key, move |key| {
+                    format!("Bullet {key} updated")
+                }))
+            }
+            Command::Variant {
+                bullet,
+                label,
+                text,
+            } => {
+                let mut item: model::DescriptionItem = super::saved(store, bullet).await?;
+                let label = super::variant_label(&item, bullet, &label)?;
+                if let Some(variant) = item.variants.iter_mut().find(|v| v.label == label) {
+                    variant.text = text;
+                }
+                let key = store.replace(Key::Db(bullet), item);
+                Ok(Outcome::new::<model::DescriptionItem>(key, move |key| {
+                    format!("Variant {label} of bullet {key} updated")
+                }))
+            }
+            Command::Job {
+                id,
+                employer,
+                title,
+                start,
+                end,
+                present,
+                bullets,
+                skills,
+            } => {
+                let mut job: model::Job = super::saved(store, id).await?;
+                let key = Key::Db(id);
+
+                if let Some(employer) = employer {
+                    job.employer = employer;
+                }
+                if let Some(title) = title {
+                    job.title = title;
+                }
+                if let Some(start) = start {
+                    job.start = start;
+                }
+                if end.is_some() || present {
+                    job.end = end;
+                }
+                for skill in super::skill_keys(store, &skills).await? {
+                    if !job.skills.contains(&skill) {
+                        job.skills.push(skill);
+                    }
+                }
+
+                // new bullets go after any the job already has
+                let parent = Parent::Job(key);
+                let mut conn = store.pool().acquire().await?;
+                let existing = model::DescriptionItem::get_all_for(&mut conn, &parent).await?;
+                let next = existing.last().map_or(0, |(_, item)| item.position + 1);
+                for (offset, text) in bullets.into_iter().enumerate() {
+                    store.add_new(model::DescriptionItem::new(
+                        parent,
+                        next + offset as i64,
+                        text,
+                    ));
+                }
+
+                let key = store.replace(key, job);
+                Ok(Outcome::new::<model::Job>(key, move |key| {
+                    format!("Job {key} updated")
+                }))
+            }
+            Command::Education {
+                id,
+                level,
+                major,
+                minor,
+                clear_minor,
+                gpa,
+                clear_gpa,
+                description,
+                clear_description,
+                courses,
+                skills,
+            } => {
+                let mut degree: model::Degree = super::saved(store, id).await?;
+                let key = Key::Db(id);
+
+                if let Some(level) = level {
+                    degree.level = level;
+                }
+                if let Some(major) = major {
+                    degree.major = major;
+                }
+                if minor.is_some() || clear_minor {
+                    degree.minor = minor;
+                }
+                if gpa.is_some() || clear_gpa {
+                    degree.gpa = gpa;
+                }
+                if description.is_some() || clear_description {
+                    degree.description = description;
+                }
+                for skill in super::skill_keys(store, &skills).await? {
+                    if !degree.skills.contains(&skill) {
+                        degree.skills.push(skill);
+                    }
+                }
+                for name in courses {
+                    store.add_new(model::Course::new(key, name, Vec::new()));
+                }
+
+                let key = store.replace(key, degree);
+                Ok(Outcome::new::<model::Degree>(key, move |key| {
+                    format!("Degree {key} updated")
+                }))
+            }
+            Command::Course { id, name, skills } => {
+                let mut course: model::Course = super::saved(store, id).await?;
+
+                if let Some(name) = name {
+                    course.name = name;
+                }
+                for skill in super::skill_keys(store, &skills).await? {
+                    if !course.skills.contains(&skill) {
+                        course.skills.push(skill);
+                    }
+                }
+
+                let key = store.replace(Key::Db(id), course);
+                Ok(Outcome::new::<model::Course>(key, move |key| {
+                    format!("Course {key} updated")
+                }))
+            }
+            Command::Project {
+                id,
+                name,
+                repository,
+                demo,
+                start,
+                end,
+                ongoing,
+                bullets,
+                skills,
+            } => {
+                let mut project: model::Project = super::saved(store, id).await?;
+                let key = Key::Db(id);
+
+                if let Some(name) = name {
+                    project.name = name;
+                }
+                if repository.is_some() {
+                    project.repository = repository;
+                }
+                if demo.is_some() {
+                    project.demo = demo;
+                }
+                if start.is_some() {
+                    project.start = start;
+                }
+                if end.is_some() || ongoing {
+                    project.end = end;
+                }
+                for skill in super::skill_keys(store, &skills).await? {
+                    if !project.skills.contains(&skill) {
+                        project.skills.push(skill);
+                    }
+                }
+
+                // new bullets go after any the project already has
+                let parent = Parent::Project(key);
+                let mut conn = store.pool().acquire().await?;
+                let existing = model::DescriptionItem::get_all_for(&mut conn, &parent).await?;
+                let next = existing.last().map_or(0, |(_, item)| item.position + 1);
+                for (offset, text) in bullets.into_iter().enumerate() {
+                    store.add_new(model::DescriptionItem::new(
+                        parent,
+                        next + offset as i64,
+                        text,
+                    ));
+                }
+
+                let key = store.replace(key, project);
+                Ok(Outcome::new::<model::Project>(key, move |key| {
+                    format!("Project {key} updated")
+                }))
+            }
+            Command::Certification {
+                id,
+                name,
+                issuer,
+                issued,
+                expires,
+                no_expiry,
+                credential_id,
+            } => {
+                let mut certification: model::Certification = super::saved(store, id).await?;
+
+                if let Some(name) = name {
+                    certification.name = name;
+                }
+                if let Some(issuer) = issuer {
+                    certification.issuer = issuer;
+                }
+                if let Some(issued) = issued {
+                    certification.issued = issued;
+                }
+                if expires.is_some() || no_expiry {
+                    certification.expires = expires;
+                }
+                if credential_id.is_some() {
+                    certification.credential_id = credential_id;
+                }
+
+                let key = store.replace(Key::Db(id), certification);
+                Ok(Outcome::new::<model::Certification>(key, move |key| {
+                    format!("Certification {key} updated")
+                }))
+            }
+            Command::Award {
+                id,
+                name,
+                issuer,
+                awarded,
+                description,
+            } => {
+                let mut award: model::Award = super::saved(store, id).await?;
+
+                if let Some(name) = name {
+                    award.name = name;
+                }
+                if issuer.is_some() {
+                    award.issuer = issuer;
+                }
+                if awarded.is_some() {
+                    award.awarded = awarded;
+                }
+                if description.is_some() {
+                    award.description = description;
+                }
+
+                let key = store.replace(Key::Db(id), award);
+                Ok(Outcome::new::<model::Award>(key, move |key| {
+                    format!("Award {key} updated")
+                }))
+            }
+            Command::Publication {
+                id,
+                title,
+                publisher,
+                published,
+                url,
+            } => {
+                let mut publication: model::Publication = super::saved(store, id).await?;
+
+                if let Some(title) = title {
+                    publication.title = title;
+                }
+                if publisher.is_some() {
+                    publication.publisher = publisher;
+                }
+                if published.is_some() {
+                    publication.published = published;
+                }
+                if url.is_some() {
+                    publication.url = url;
+                }
+
+                let key = store.replace(Key::Db(id), publication);
+                Ok(Outcome::new::<model::Publication>(key, move |key| {
+                    format!("Publication {key} updated")
+                }))
+            }
+            Command::Volunteer {
+                id,
+                organization,
+                role,
+                start,
+                end,
+                ongoing,
+                description,
+            } => {
+                let mut volunteer: model::Volunteer = super::saved(store, id).await?;
+
+                if let Some(organization) = organization {
+                    volunteer.organization = organization;
+                }
+                if let Some(role) = role {
+                    volunteer.role = role;
+                }
+                if let Some(start) = start {
+                    volunteer.start = start;
+                }
+                if end.is_some() || ongoing {
+                    volunteer.end = end;
+                }
+                if description.is_some() {
+                    volunteer.description = description;
+                }
+
+                let key = store.replace(Key::Db(id), volunteer);
+                Ok(Outcome::new::<model::Volunteer>(key, move |key| {
+                    format!("Volunteer work {key} updated")
+                }))
+            }
+            Command::Language {
+                id,
+                name,
+                proficiency,
+            } => {
+                let mut language: model::Language = super::saved(store, id).await?;
+
+                if let Some(name) = name {
+                    language.name = name;
+                }
+                if let Some(proficiency) = proficiency {
+                    language.proficiency = proficiency;
+                }
+
+                let key = store.replace(Key::Db(id), language);
+                Ok(Outcome::new::<model::Language>(key, move |key| {
+                    format!("Language {key} updated")
+                }))
+            }
+            Command::Skill {
+                id,
+                name,
+                category,
+                uncategorized,
+                aliases,
+                remove_aliases,
+            } => {
+                let mut skill: model::Skill = super::saved(store, id).await?;
+
+                let names: Vec<_> = name.iter().chain(&aliases).map(String::as_str).collect();
+                super::check_skill_names(store, &names, Some(id)).await?;
+                if let Some(name) = name {
+                    skill.name = name;
+                }
+                // a skill's name doesn't need to be an alias of itself too
+                let own_name = skill.name.to_lowercase();
+                skill.aliases.retain(|alias| {
+                    alias.to_lowercase() != own_name
+                        && !remove_aliases
+                            .iter()
+                            .any(|removed| removed.to_lowercase() == alias.to_lowercase())
+                });
+                for alias in aliases {
+                    if !skill.is_called(&alias) {
+                        skill.aliases.push(alias);
+                    }
+                }
+                if let Some(category) = category {
+                    skill.category = Some(super::category_key(store, &category).await?);
+                }
+                if uncategorized {
+                    skill.category = None;
+                }
+
+                let key = store.replace(Key::Db(id), skill);
+                Ok(Outcome::new::<model::Skill>(key, move |key| {
+                    format!("Skill {key} updated")
+                }))
+            }
+            Command::Category {
+                id,
+                name,
+                parent,
+                top_level,
+            } => {
+                let mut category: model::SkillCategory = super::saved(store, id).await?;
+
+                if let Some(name) = name {
+                    let mut conn = store.pool().acquire().await?;
+                    match model::SkillCategory::get_one_by_name(&mut conn, &name).await? {
+                        Some((other, _)) if other != id => {
+                            anyhow::bail!("Skill category {name} already exists with id {other}")
+                        }
+                        _ => category.name = name,
+                    }
+                }
+                if let Some(parent) = parent {
+                    let parent = super::category_key(store, &parent).await?;
+                    // walk up from the new parent to make sure categories don't nest in a loop
+                    let mut above = Some(parent);
+                    while let Some(key) = above {
+                        if key == Key::Db(id) {
+                            anyhow::bail!("A category can't be nested under itself");
+                        }
+                        let nested: model::SkillCategory =
+                            super::saved(store, key.db_id()?).await?;
+                        above = nested.parent;
+                    }
+                    category.parent = Some(parent);
+                }
+                if top_level {
+                    category.parent = None;
+                }
+
+                let key = store.replace(Key::Db(id), category);
+                Ok(Outcome::new::<model::SkillCategory>(key, move |key| {
+                    format!("Skill Category {key} updated")
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use anyhow::bail;
+use clap::Args;
+use sqlx::SqlitePool;
+
+use crate::export::{Document, Format, Version};
+
+#[derive(Debug, Args)]
+pub struct Export {
+    /// id of the resume to export
+    id: i64,
+    /// file type to export as
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+    /// path of the file to write, recording what was written as a new version of the resume;
+    /// writes to stdout, recording nothing, if not given
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// export a version recorded by an earlier export exactly as it was, instead of the resume as
+    /// it is now
+    #[arg(long)]
+    version: Option<i64>,
+}
+
+impl Export {
+    /// Export the resume, recording what was written to a file as a new version of it unless it's a
+    /// dry run, a past version being exported again, or the same as the last version exported.
+    ///
+    /// Exporting to stdout is only a preview, so never records anything.
+    pub async fn handle(
+        &self,
+        pool: &SqlitePool,
+        dry_run: bool,
+        writer: &mut impl Write,
+    ) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let content = match self.version {
+            Some(number) => {
+                let version = Version::get(&mut conn, self.id, number).await?;
+                if version.format != self.format {
+                    bail!(
+                        "Version {number} of resume {} was exported as {:?}",
+                        self.id,
+                        version.format
+                    );
+                }
+                version.content
+            }
+            None => {
+                let document = Document::load(&mut conn, self.id).await?;
+                let mut content = Vec::new();
+                self.format.write(&document, &mut content)?;
+                content
+            }
+        };
+
+        match &self.output {
+            Some(path) => {
+                let mut file = BufWriter::new(File::create(path)?);
+                file.write_all(&content)?;
+                file.flush()?;
+            }
+            None => writer.write_all(&content)?,
+        }
+        // only once it's been written is it certain what was exported
+        let version = match self.version {
+            None if !dry_run && self.output.is_some() => {
+                let version = Version::record(&mut conn, self.id, self.format, content).await?;
+                Some(version.number)
+            }
+            number => number,
+        };
+
+        if let Some(path) = &self.output {
+            write!(writer, "Resume {}", self.id)?;
+            if let Some(number) = version {
+                write!(writer, " version {number}")?;
+            }
+            writeln!(writer, " exported to {}", path.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn only_exports_to_a_file_are_recorded() {
+    use crate::model::{self, DbModel};
+
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    let id = model::Resume::new("Mine".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    drop(conn);
+    let path = std::env::temp_dir().join(format!("res-gen-export-{}.md", std::process::id()));
+    let export = |output| Export {
+        id,
+        format: Format::Markdown,
+        output,
+        version: None,
+    };
+
+    let mut preview = Vec::new();
+    export(None)
+        .handle(&pool, false, &mut preview)
+        .await
+        .unwrap();
+    let mut conn = pool.acquire().await.unwrap();
+    assert!(Version::all_for(&mut conn, id).await.unwrap().is_empty());
+    drop(conn);
+    export(Some(path.clone()))
+        .handle(&pool, false, &mut Vec::new())
+        .await
+        .unwrap();
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(preview, b"# Mine\n");
+    let mut conn = pool.acquire().await.unwrap();
+    let versions = Version::all_for(&mut conn, id).await.unwrap();
+    assert_eq!(versions.len(), 1);
+    assert_eq!(versions[0].content, written);
+}
@@ -0,0 +1,89 @@
+use std::io::Write;
+
+use clap::Args;
+use serde_json::Value;
+use sqlx::SqlitePool;
+
+use crate::history::{self, JournalEntry, RowChange};
+
+/// Show what changed when, oldest first
+#[derive(Debug, Args)]
+pub struct History {
+    /// only show changes to one row, given as `<model>:<id>`, e.g. `job:1` or `bullet:3`
+    #[arg(short, long, value_parser = parse_item)]
+    item: Option<(String, i64)>,
+}
+
+impl History {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let item = self.item.as_ref().map(|(model, id)| (model.as_str(), *id));
+        let entries = history::journal(&mut conn, item).await?;
+
+        if entries.is_empty() {
+            writeln!(writer, "No changes found")?;
+        }
+        for entry in entries {
+            write_entry(&entry, writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a row given as `<model>:<id>`, where bullets can be called `bullet` for short.
+fn parse_item(s: &str) -> Result<(String, i64), String> {
+    let (model, id) = s
+        .split_once(':')
+        .ok_or_else(|| format!("{s} should look like <model>:<id>, e.g. job:1"))?;
+    let id = id.parse().map_err(|_| format!("{id} is not a valid id"))?;
+    let model = match model.to_lowercase().as_str() {
+        "bullet" => "DescriptionItem".into(),
+        _ => model.into(),
+    };
+
+    Ok((model, id))
+}
+
+fn write_entry(entry: &JournalEntry, writer: &mut impl Write) -> anyhow::Result<()> {
+    writeln!(
+        writer,
+        "#{} {} [session {}] {}{}",
+        entry.id,
+        entry.recorded_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        entry.session,
+        entry.change,
+        if entry.undone { " (undone)" } else { "" }
+    )?;
+    for row in &entry.rows {
+        write_row(row, writer)?;
+    }
+
+    Ok(())
+}
+
+fn write_row(row: &RowChange, writer: &mut impl Write) -> anyhow::Result<()> {
+    let RowChange {
+        model,
+        id,
+        before,
+        after,
+    } = row;
+    match (before, after) {
+        (None, Some(after)) => writeln!(writer, "  + {model} {id}: {after}")?,
+        (Some(before), None) => writeln!(writer, "  - {model} {id}: {before}")?,
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            // only show the fields that changed, with what they said before
+            for (field, new) in after {
+                let old = before.get(field).unwrap_or(&Value::Null);
+                if old != new {
+                    writeln!(writer, "  ~ {model} {id} {field}: {old} -> {new}")?;
+                }
+            }
+        }
+        (Some(before), Some(after)) => writeln!(writer, "  ~ {model} {id}: {before} -> {after}")?,
+        (None, None) => (),
+    }
+
+    Ok(())
+}
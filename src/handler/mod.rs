@@ -1,7 +1,487 @@
+use anyhow::Context;
+use clap::{Subcommand, ValueEnum};
+
+use crate::model::{self, ListedSection};
+use crate::state::{AddNew, AppState, Get, Key, KeyMap, Remove, Replace};
+use crate::store::Store;
+
 pub mod add;
-// pub mod show;
+pub mod delete;
+pub mod edit;
+pub mod export;
+pub mod history;
+pub mod show;
+pub mod status;
+pub mod undo;
 
 // Make handers publicly available w/out having to dig one level deeper
-// pub use add::Add;
 pub use add::Add;
-// pub use show::Show;
+pub use delete::Delete;
+pub use edit::Edit;
+pub use export::Export;
+pub use history::History;
+pub use show::Show;
+pub use status::Status;
+pub use undo::{Redo, Undo};
+
+/// What a command changed, to tell the user once it's saved.
+///
+/// New values only get their db id on save, so the message is made from the key the changed
+/// value ends up under, letting users pass it straight to their next command.
+pub struct Outcome {
+    key: Key,
+    /// Where the value under `key` is found after saving, which depends on it's type
+    saved: fn(&KeyMap, Key) -> Key,
+    message: Box<dyn FnOnce(Key) -> String>,
+}
+
+impl Outcome {
+    pub fn new<V: 'static>(key: Key, message: impl FnOnce(Key) -> String + 'static) -> Self {
+        Self {
+            key,
+            saved: KeyMap::get::<V>,
+            message: Box::new(message),
+        }
+    }
+
+    /// Tell the user what changed, given where saving moved values to.
+    pub fn write(self, keys: &KeyMap, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        writeln!(writer, "{}", (self.message)((self.saved)(keys, self.key)))
+    }
+}
+
+/// Get the saved value with the given id as of the latest change to it, failing if there's none.
+async fn saved<V>(store: &mut Store, id: i64) -> anyhow::Result<V>
+where
+    V: model::DbModel + Clone + 'static,
+    AppState: Get<V, Key>,
+{
+    store
+        .get(&Key::Db(id))
+        .await?
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No {} with id {id}", V::TABLE))
+}
+
+/// Move the value with the given id to `position` amongst it's siblings, given in their current
+/// order, renumbering them all from 0 so none share a position or leave a gap.
+///
+/// `moved` is the moved value as edited so far, so only gets it's position set, but any sibling
+/// that ends up in a new position is replaced.
+async fn reposition<V>(
+    store: &mut Store,
+    siblings: impl IntoIterator<Item = i64>,
+    (id, moved): (i64, &mut V),
+    position: usize,
+    position_of: fn(&mut V) -> &mut i64,
+) -> anyhow::Result<()>
+where
+    V: model::DbModel + Clone + 'static,
+    AppState: Get<V, Key>,
+    Store: Replace<V, Key>,
+{
+    let mut order: Vec<i64> = siblings.into_iter().filter(|&s| s != id).collect();
+    order.insert(position.min(order.len()), id);
+
+    for (index, sibling) in (0..).zip(order) {
+        if sibling == id {
+            *position_of(moved) = index;
+            continue;
+        }
+        let mut value: V = saved(store, sibling).await?;
+        if *position_of(&mut value) != index {
+            *position_of(&mut value) = index;
+            store.replace(Key::Db(sibling), value);
+        }
+    }
+
+    Ok(())
+}
+
+/// Find keys for each of the named skills, adding a new skill for any names not found.
+///
+/// Names are matched against each skill's aliases as well, so `JS` & `JavaScript` give the same key.
+async fn skill_keys(store: &mut Store, names: &[String]) -> anyhow::Result<Vec<Key>> {
+    let mut conn = store.pool().acquire().await?;
+    let mut keys = Vec::with_capacity(names.len());
+    let mut seen: Vec<String> = Vec::with_capacity(names.len());
+
+    for name in names {
+        // skip repeated names so we don't add the same new skill twice
+        if seen.contains(&name.to_lowercase()) {
+            continue;
+        }
+        seen.push(name.to_lowercase());
+
+        let key = match model::Skill::get_one_by_name(&mut conn, name).await? {
+            Some((id, _)) => Key::Db(id),
+            None => store.add_new(model::Skill::new(name.clone(), None)),
+        };
+        // different names can be aliases for the same skill
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Find the key for the named skill category, which must already exist.
+async fn category_key(store: &Store, name: &str) -> anyhow::Result<Key> {
+    let mut conn = store.pool().acquire().await?;
+
+    match model::SkillCategory::get_one_by_name(&mut conn, name).await? {
+        Some((id, _)) => Ok(Key::Db(id)),
+        None => anyhow::bail!("No skill category named {name}, add it first"),
+    }
+}
+
+/// Make sure none of the given names are already the name or an alias of a skill, other than the
+/// skill with the given id.
+async fn check_skill_names(store: &Store, names: &[&str], own: Option<i64>) -> anyhow::Result<()> {
+    let mut conn = store.pool().acquire().await?;
+
+    for name in names {
+        if let Some((id, skill)) = model::Skill::get_one_by_name(&mut conn, name).await? {
+            if Some(id) != own {
+                anyhow::bail!("{name} already refers to skill {id}: {}", skill.name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Find the label of the given bullet's variant matching the given one, ignoring case.
+fn variant_label(item: &model::DescriptionItem, id: i64, label: &str) -> anyhow::Result<String> {
+    match item.variant(label) {
+        Some(variant) => Ok(variant.label.clone()),
+        None => anyhow::bail!("Bullet {id} has no variant labelled {label}"),
+    }
+}
+
+/// Resume sections made up of a selection of master data items.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum SectionKind {
+    Skills,
+    Education,
+    Experience,
+    Projects,
+    Certifications,
+    Awards,
+    Publications,
+    Volunteering,
+    Languages,
+}
+
+impl SectionKind {
+    /// Find keys for each of the given items, in order.
+    ///
+    /// Skills are given by name, adding any that don't exist yet, while everything else is given
+    /// by id.
+    async fn keys(&self, store: &mut Store, items: &[String]) -> anyhow::Result<Vec<Key>> {
+        let keys = match self {
+            SectionKind::Skills => skill_keys(store, items).await?,
+            _ => items
+                .iter()
+                .map(|id| {
+                    id.parse()
+                        .map(Key::Db)
+                        .with_context(|| format!("{id} is not a valid id"))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        };
+        for (i, key) in keys.iter().enumerate() {
+            if keys[..i].contains(key) {
+                anyhow::bail!("{} is listed more than once", items[i]);
+            }
+        }
+
+        Ok(keys)
+    }
+
+    /// What was done to this section of a resume, the section being under the given key.
+    fn outcome(self, key: Key, done: &'static str) -> Outcome {
+        let message = move |key| format!("{self:?} Section {key} {done}");
+        match self {
+            SectionKind::Skills => Outcome::new::<model::SkillsSection>(key, message),
+            SectionKind::Education => Outcome::new::<model::EducationSection>(key, message),
+            SectionKind::Experience => Outcome::new::<model::ExperienceSection>(key, message),
+            SectionKind::Projects => Outcome::new::<model::ProjectSection>(key, message),
+            SectionKind::Certifications => {
+                Outcome::new::<model::CertificationSection>(key, message)
+            }
+            SectionKind::Awards => Outcome::new::<model::AwardSection>(key, message),
+            SectionKind::Publications => Outcome::new::<model::PublicationSection>(key, message),
+            SectionKind::Volunteering => Outcome::new::<model::VolunteerSection>(key, message),
+            SectionKind::Languages => Outcome::new::<model::LanguageSection>(key, message),
+        }
+    }
+
+    /// Add this section to the given resume, listing the given items.
+    fn add(&self, store: &mut Store, resume: Key, items: Vec<Key>) -> Key {
+        fn add<S: ListedSection>(store: &mut Store, resume: Key, items: Vec<Key>) -> Key
+        where
+            Store: AddNew<S, Key>,
+        {
+            store.add_new(S::with_items(resume, items))
+        }
+
+        match self {
+            SectionKind::Skills => add::<model::SkillsSection>(store, resume, items),
+            SectionKind::Education => add::<model::EducationSection>(store, resume, items),
+            SectionKind::Experience => add::<model::ExperienceSection>(store, resume, items),
+            SectionKind::Projects => add::<model::ProjectSection>(store, resume, items),
+            SectionKind::Certifications => add::<model::CertificationSection>(store, resume, items),
+            SectionKind::Awards => add::<model::AwardSection>(store, resume, items),
+            SectionKind::Publications => add::<model::PublicationSection>(store, resume, items),
+            SectionKind::Volunteering => add::<model::VolunteerSection>(store, resume, items),
+            SectionKind::Languages => add::<model::LanguageSection>(store, resume, items),
+        }
+    }
+
+    /// Replace the items listed in this section of the given resume.
+    async fn replace(
+        &self,
+        store: &mut Store,
+        resume: Key,
+        items: Vec<Key>,
+    ) -> anyhow::Result<Key> {
+        async fn replace<S: ListedSection + Clone + 'static>(
+            store: &mut Store,
+            resume: Key,
+            items: Vec<Key>,
+        ) -> anyhow::Result<Key>
+        where
+            Store: Replace<S, Key>,
+            AppState: Get<S, Key>,
+        {
+            let mut conn = store.pool().acquire().await?;
+            let (id, _) = S::get_one_for(&mut conn, &resume)
+                .await?
+                .context("The resume doesn't have that section yet, add it first")?;
+            drop(conn);
+            // load the section as it was, so saving can tell if anyone else changed it since
+            saved::<S>(store, id).await?;
+
+            Ok(store.replace(Key::Db(id), S::with_items(resume, items)))
+        }
+
+        match self {
+            SectionKind::Skills => replace::<model::SkillsSection>(store, resume, items).await,
+            SectionKind::Education => {
+                replace::<model::EducationSection>(store, resume, items).await
+            }
+            SectionKind::Experience => {
+                replace::<model::ExperienceSection>(store, resume, items).await
+            }
+            SectionKind::Projects => replace::<model::ProjectSection>(store, resume, items).await,
+            SectionKind::Certifications => {
+                replace::<model::CertificationSection>(store, resume, items).await
+            }
+            SectionKind::Awards => replace::<model::AwardSection>(store, resume, items).await,
+            SectionKind::Publications => {
+                replace::<model::PublicationSection>(store, resume, items).await
+            }
+            SectionKind::Volunteering => {
+                replace::<model::VolunteerSection>(store, resume, items).await
+            }
+            SectionKind::Languages => replace::<model::LanguageSection>(store, resume, items).await,
+        }
+    }
+
+    /// Delete this section of the given resume.
+    async fn delete(&self, store: &mut Store, resume: Key) -> anyhow::Result<Key> {
+        async fn delete<S: ListedSection + Clone + Sync + 'static>(
+            store: &mut Store,
+            resume: Key,
+        ) -> anyhow::Result<Key>
+        where
+            Store: Remove<S, Key>,
+            AppState: Get<S, Key>,
+        {
+            let mut conn = store.pool().acquire().await?;
+            let (id, _) = S::get_one_for(&mut conn, &resume)
+                .await?
+                .context("The resume doesn't have that section")?;
+            drop(conn);
+            // load the section so state knows what's being deleted
+            saved::<S>(store, id).await?;
+
+            Ok(Remove::<S, Key>::remove(store, Key::Db(id)))
+        }
+
+        match self {
+            SectionKind::Skills => delete::<model::SkillsSection>(store, resume).await,
+            SectionKind::Education => delete::<model::EducationSection>(store, resume).await,
+            SectionKind::Experience => delete::<model::ExperienceSection>(store, resume).await,
+            SectionKind::Projects => delete::<model::ProjectSection>(store, resume).await,
+            SectionKind::Certifications => {
+                delete::<model::CertificationSection>(store, resume).await
+            }
+            SectionKind::Awards => delete::<model::AwardSection>(store, resume).await,
+            SectionKind::Publications => delete::<model::PublicationSection>(store, resume).await,
+            SectionKind::Volunteering => delete::<model::VolunteerSection>(store, resume).await,
+            SectionKind::Languages => delete::<model::LanguageSection>(store, resume).await,
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum ContactCommand {
+    Phone {
+        /// phone number as `+<country code> <number>`, e.g. `+1 555-555-5555`
+        #[arg(value_parser = model::ContactMethod::parse_phone)]
+        number: model::ContactMethod,
+    },
+    Email {
+        #[arg(value_parser = model::ContactMethod::parse_email)]
+        address: model::ContactMethod,
+    },
+    Link {
+        /// text to display for the link
+        #[arg(value_parser = model::ContactMethod::parse_link_text)]
+        display: String,
+        /// http or https url the link points to
+        #[arg(value_parser = model::ContactMethod::parse_href)]
+        href: url::Url,
+    },
+}
+
+impl ContactCommand {
+    /// The contact method described.
+    fn method(self) -> model::ContactMethod {
+        match self {
+            ContactCommand::Phone { number: method }
+            | ContactCommand::Email { address: method } => method,
+            ContactCommand::Link { display, href } => model::ContactMethod::Link { display, href },
+        }
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn reposition_renumbers_every_sibling() {
+    let mut store = Store::new(model::test_pool().await);
+    let job = store.add_new(model::Job::new(
+        "Acme".into(),
+        "Developer".into(),
+        chrono::NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+        None,
+        Vec::new(),
+    ));
+    let parent = model::Parent::Job(job);
+    for (position, text) in [(0, "one"), (0, "two"), (3, "three")] {
+        store.add_new(model::DescriptionItem::new(parent, position, text.into()));
+    }
+    store.save().await.unwrap();
+
+    let mut three: model::DescriptionItem = saved(&mut store, 3).await.unwrap();
+    reposition(&mut store, [1, 2, 3], (3, &mut three), 1, |i| {
+        &mut i.position
+    })
+    .await
+    .unwrap();
+    store.replace(Key::Db(3), three);
+    store.save().await.unwrap();
+
+    let mut conn = store.pool().acquire().await.unwrap();
+    let items = model::DescriptionItem::get_all_for(&mut conn, &model::Parent::Job(Key::Db(1)))
+        .await
+        .unwrap();
+    let order: Vec<_> = items
+        .iter()
+        .map(|(id, item)| (*id, item.position))
+        .collect();
+    assert_eq!(order, vec![(1, 0), (3, 1), (2, 2)]);
+}
+
+#[cfg(test)]
+#[test]
+fn project_links_must_be_web_urls() {
+    use clap::Parser;
+
+    let parse = |command: &str, link: &str| {
+        let (arg, flag) = match command {
+            "add" => ("res-gen", "--repository"),
+            _ => ("1", "--demo"),
+        };
+        crate::Args::try_parse_from(["res-gen", command, "project", arg, flag, link])
+    };
+    for command in ["add", "edit"] {
+        assert!(parse(command, "https://github.com/me/res-gen").is_ok());
+        assert!(parse(command, "github.com/me/res-gen").is_err());
+        assert!(parse(command, "ftp://me").is_err());
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn publication_links_must_be_web_urls() {
+    use clap::Parser;
+
+    let parse = |command: &str, link: &str| {
+        let arg = if command == "add" { "Rust tips" } else { "1" };
+        crate::Args::try_parse_from(["res-gen", command, "publication", arg, "--url", link])
+    };
+    for command in ["add", "edit"] {
+        assert!(parse(command, "https://blog.me/rust-tips").is_ok());
+        assert!(parse(command, "blog.me/rust-tips").is_err());
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn nesting_a_new_category_only_needs_one_connection() {
+    use clap::Parser;
+
+    // the test pool only has the one connection, so holding it while looking up the parent hangs
+    let mut store = Store::new(model::test_pool().await);
+    store.add_new(model::SkillCategory::new("Languages".into(), None));
+    store.save().await.unwrap();
+
+    let args = [
+        "res-gen",
+        "add",
+        "category",
+        "Compiled",
+        "--parent",
+        "Languages",
+    ];
+    let crate::Command::Add(add) = crate::Args::try_parse_from(args).unwrap().cmd else {
+        panic!("should've parsed as an add");
+    };
+    add.handle(&mut store).await.unwrap();
+    store.save().await.unwrap();
+
+    let mut conn = store.pool().acquire().await.unwrap();
+    let (_, nested) = model::SkillCategory::get_one_by_name(&mut conn, "Compiled")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(nested.parent, Some(Key::Db(1)));
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn deleting_a_summary_only_needs_one_connection() {
+    use clap::Parser;
+
+    let mut store = Store::new(model::test_pool().await);
+    let resume = store.add_new(model::Resume::new("Backend".into()));
+    store.add_new(model::SummarySection::new(resume, "Builds APIs".into()));
+    store.save().await.unwrap();
+
+    let args = ["res-gen", "delete", "summary", "--resume", "1"];
+    let crate::Command::Delete(delete) = crate::Args::try_parse_from(args).unwrap().cmd else {
+        panic!("should've parsed as a delete");
+    };
+    delete.handle(&mut store).await.unwrap();
+    store.save().await.unwrap();
+
+    let mut conn = store.pool().acquire().await.unwrap();
+    let summary = model::SummarySection::get_one_for(&mut conn, &Key::Db(1))
+        .await
+        .unwrap();
+    assert!(summary.is_none());
+}
@@ -0,0 +1,44 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::SqlitePool;
+
+use crate::model::{self, DbModel};
+
+#[derive(Debug, Args)]
+pub struct Award {
+    /// id of the award to show, shows all awards if not given
+    id: Option<i64>,
+}
+
+impl Award {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let awards = match self.id {
+            Some(id) => vec![(id, model::Award::get_one_by_id(&mut conn, id).await?)],
+            None => model::Award::get_all(&mut conn).await?,
+        };
+
+        for (id, award) in awards {
+            write_award(writer, id, &award)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_award(writer: &mut impl Write, id: i64, award: &model::Award) -> anyhow::Result<()> {
+    write!(writer, "Award {id}: {}", award.name)?;
+    if let Some(issuer) = &award.issuer {
+        write!(writer, " from {issuer}")?;
+    }
+    if let Some(awarded) = award.awarded {
+        write!(writer, ", {awarded}")?;
+    }
+    writeln!(writer)?;
+    if let Some(description) = &award.description {
+        writeln!(writer, "  {description}")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::SqlitePool;
+
+use crate::model::{self, DbModel};
+
+#[derive(Debug, Args)]
+pub struct Certification {
+    /// id of the certification to show, shows all certifications if not given
+    id: Option<i64>,
+}
+
+impl Certification {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let certifications = match self.id {
+            Some(id) => vec![(
+                id,
+                model::Certification::get_one_by_id(&mut conn, id).await?,
+            )],
+            None => model::Certification::get_all(&mut conn).await?,
+        };
+
+        for (id, certification) in certifications {
+            write_certification(writer, id, &certification)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_certification(
+    writer: &mut impl Write,
+    id: i64,
+    certification: &model::Certification,
+) -> anyhow::Result<()> {
+    write!(
+        writer,
+        "Certification {id}: {} from {}, issued {}",
+        certification.name, certification.issuer, certification.issued
+    )?;
+    if let Some(expires) = certification.expires {
+        write!(writer, ", expires {expires}")?;
+    }
+    writeln!(writer)?;
+    if let Some(credential_id) = &certification.credential_id {
+        writeln!(writer, "  credential id: {credential_id}")?;
+    }
+
+    Ok(())
+}
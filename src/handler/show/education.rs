@@ -0,0 +1,59 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::write_skills;
+use crate::model::{self, DbModel};
+use crate::state::Key;
+
+#[derive(Debug, Args)]
+pub struct Education {
+    /// id of the degree to show, shows all degrees if not given
+    id: Option<i64>,
+}
+
+impl Education {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let degrees = match self.id {
+            Some(id) => vec![(id, model::Degree::get_one_by_id(&mut conn, id).await?)],
+            None => model::Degree::get_all(&mut conn).await?,
+        };
+
+        for (id, degree) in degrees {
+            write_degree(&mut conn, writer, id, &degree).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write a degree, followed by it's developed skills & courses, along with the skills each
+/// course developed.
+async fn write_degree(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    id: i64,
+    degree: &model::Degree,
+) -> anyhow::Result<()> {
+    write!(writer, "Degree {id}: {} in {}", degree.level, degree.major)?;
+    if let Some(minor) = &degree.minor {
+        write!(writer, ", minor in {minor}")?;
+    }
+    if let Some(gpa) = degree.gpa {
+        write!(writer, " ({gpa:.2} GPA)")?;
+    }
+    writeln!(writer)?;
+    if let Some(description) = &degree.description {
+        writeln!(writer, "  {description}")?;
+    }
+    write_skills(conn, writer, "  ", &degree.skills).await?;
+
+    for (id, course) in model::Course::get_all_for(conn, &Key::Db(id)).await? {
+        writeln!(writer, "  Course {id}: {}", course.name)?;
+        write_skills(conn, writer, "    ", &course.skills).await?;
+    }
+
+    Ok(())
+}
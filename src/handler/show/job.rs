@@ -0,0 +1,52 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::{date_range, write_bullets, write_skills};
+use crate::model::{self, DbModel, Parent};
+use crate::state::Key;
+
+#[derive(Debug, Args)]
+pub struct Job {
+    /// id of the job to show, shows all jobs if not given
+    id: Option<i64>,
+}
+
+impl Job {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let jobs = match self.id {
+            Some(id) => vec![(id, model::Job::get_one_by_id(&mut conn, id).await?)],
+            None => model::Job::get_all(&mut conn).await?,
+        };
+
+        for (id, job) in jobs {
+            write_job(&mut conn, writer, id, &job).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write a job, followed by the skills practiced & bullets describing it.
+async fn write_job(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    id: i64,
+    job: &model::Job,
+) -> anyhow::Result<()> {
+    writeln!(
+        writer,
+        "Job {id}: {title} at {employer}, {dates}",
+        title = job.title,
+        employer = job.employer,
+        dates = date_range(Some(job.start), job.end),
+    )?;
+
+    write_skills(conn, writer, "  ", &job.skills).await?;
+
+    write_bullets(conn, writer, &Parent::Job(Key::Db(id))).await?;
+
+    Ok(())
+}
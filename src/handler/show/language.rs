@@ -0,0 +1,42 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::SqlitePool;
+
+use crate::model::{self, DbModel};
+
+#[derive(Debug, Args)]
+pub struct Language {
+    /// id of the language to show, shows all languages if not given
+    id: Option<i64>,
+}
+
+impl Language {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let languages = match self.id {
+            Some(id) => vec![(id, model::Language::get_one_by_id(&mut conn, id).await?)],
+            None => model::Language::get_all(&mut conn).await?,
+        };
+
+        for (id, language) in languages {
+            write_language(writer, id, &language)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_language(
+    writer: &mut impl Write,
+    id: i64,
+    language: &model::Language,
+) -> anyhow::Result<()> {
+    writeln!(
+        writer,
+        "Language {id}: {}, {}",
+        language.name, language.proficiency
+    )?;
+
+    Ok(())
+}
@@ -1,12 +1,34 @@
 use std::io::Write;
 
+use chrono::NaiveDate;
 use clap::{Args, Subcommand};
 use log::debug;
-use sqlx::SqlitePool;
+use sqlx::{SqliteConnection, SqlitePool};
 
-mod one;
+use crate::model::{self, DbModel};
+use crate::state::Key;
 
-use one::One;
+mod award;
+mod certification;
+mod education;
+mod job;
+mod language;
+mod project;
+mod publication;
+mod resume;
+mod skill;
+mod volunteer;
+
+use award::Award;
+use certification::Certification;
+use education::Education;
+use job::Job;
+use language::Language;
+use project::Project;
+use publication::Publication;
+use resume::Resume;
+use skill::Skill;
+use volunteer::Volunteer;
 
 #[derive(Debug, Args)]
 pub struct Show {
@@ -16,16 +38,80 @@ pub struct Show {
 
 #[derive(Debug, Subcommand)]
 enum Command {
-    One(One),
-    // All(All),
+    Resume(Resume),
+    Skill(Skill),
+    Job(Job),
+    Education(Education),
+    Project(Project),
+    Certification(Certification),
+    Award(Award),
+    Publication(Publication),
+    Volunteer(Volunteer),
+    Language(Language),
 }
 
 impl Show {
     pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
         debug!("In show handler w/: {:#?}", &self.cmd);
         match &self.cmd {
-            Command::One(one) => one.handle(pool, writer).await,
-            // Command::All(all) => all.handle(pool, writer).await,
+            Command::Resume(resume) => resume.handle(pool, writer).await,
+            Command::Skill(skill) => skill.handle(pool, writer).await,
+            Command::Job(job) => job.handle(pool, writer).await,
+            Command::Education(education) => education.handle(pool, writer).await,
+            Command::Project(project) => project.handle(pool, writer).await,
+            Command::Certification(certification) => certification.handle(pool, writer).await,
+            Command::Award(award) => award.handle(pool, writer).await,
+            Command::Publication(publication) => publication.handle(pool, writer).await,
+            Command::Volunteer(volunteer) => volunteer.handle(pool, writer).await,
+            Command::Language(language) => language.handle(pool, writer).await,
+        }
+    }
+}
+
+/// Write the names of the given skills on a single line, if there are any.
+async fn write_skills(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    indent: &str,
+    keys: &[Key],
+) -> anyhow::Result<()> {
+    let mut skills = Vec::with_capacity(keys.len());
+    for key in keys {
+        skills.push(
+            model::Skill::get_one_by_id(&mut *conn, key.db_id()?)
+                .await?
+                .name,
+        );
+    }
+    if !skills.is_empty() {
+        writeln!(writer, "{indent}skills: {}", skills.join(", "))?;
+    }
+
+    Ok(())
+}
+
+/// Write the bullets describing the given job or project, each followed by its variants.
+async fn write_bullets(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    parent: &model::Parent,
+) -> anyhow::Result<()> {
+    for (id, bullet) in model::DescriptionItem::get_all_for(conn, parent).await? {
+        writeln!(writer, "  - {id}: {}", bullet.text)?;
+        for variant in &bullet.variants {
+            writeln!(writer, "      {}: {}", variant.label, variant.text)?;
         }
     }
+
+    Ok(())
+}
+
+/// Describe the time between the given dates, treating a missing end as ongoing.
+fn date_range(start: Option<NaiveDate>, end: Option<NaiveDate>) -> String {
+    match (start, end) {
+        (Some(start), Some(end)) => format!("{start} - {end}"),
+        (Some(start), None) => format!("{start} - present"),
+        (None, Some(end)) => format!("until {end}"),
+        (None, None) => String::new(),
+    }
 }
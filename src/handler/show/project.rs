@@ -0,0 +1,55 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::{date_range, write_bullets, write_skills};
+use crate::model::{self, DbModel, Parent};
+use crate::state::Key;
+
+#[derive(Debug, Args)]
+pub struct Project {
+    /// id of the project to show, shows all projects if not given
+    id: Option<i64>,
+}
+
+impl Project {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let projects = match self.id {
+            Some(id) => vec![(id, model::Project::get_one_by_id(&mut conn, id).await?)],
+            None => model::Project::get_all(&mut conn).await?,
+        };
+
+        for (id, project) in projects {
+            write_project(&mut conn, writer, id, &project).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write a project & it's links, followed by the skills practiced & bullets describing it.
+async fn write_project(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    id: i64,
+    project: &model::Project,
+) -> anyhow::Result<()> {
+    write!(writer, "Project {id}: {}", project.name)?;
+    if project.start.is_some() || project.end.is_some() {
+        write!(writer, ", {}", date_range(project.start, project.end))?;
+    }
+    writeln!(writer)?;
+    if let Some(repository) = &project.repository {
+        writeln!(writer, "  repository: {repository}")?;
+    }
+    if let Some(demo) = &project.demo {
+        writeln!(writer, "  demo: {demo}")?;
+    }
+    write_skills(conn, writer, "  ", &project.skills).await?;
+
+    write_bullets(conn, writer, &Parent::Project(Key::Db(id))).await?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::SqlitePool;
+
+use crate::model::{self, DbModel};
+
+#[derive(Debug, Args)]
+pub struct Publication {
+    /// id of the publication to show, shows all publications if not given
+    id: Option<i64>,
+}
+
+impl Publication {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let publications = match self.id {
+            Some(id) => vec![(id, model::Publication::get_one_by_id(&mut conn, id).await?)],
+            None => model::Publication::get_all(&mut conn).await?,
+        };
+
+        for (id, publication) in publications {
+            write_publication(writer, id, &publication)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_publication(
+    writer: &mut impl Write,
+    id: i64,
+    publication: &model::Publication,
+) -> anyhow::Result<()> {
+    write!(writer, "Publication {id}: {}", publication.title)?;
+    if let Some(publisher) = &publication.publisher {
+        write!(writer, " in {publisher}")?;
+    }
+    if let Some(published) = publication.published {
+        write!(writer, ", {published}")?;
+    }
+    writeln!(writer)?;
+    if let Some(url) = &publication.url {
+        writeln!(writer, "  url: {url}")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::{SqliteConnection, SqlitePool};
+
+use crate::export::Version;
+use crate::model::{self, DbModel, ListedSection, Parent};
+use crate::state::Key;
+
+#[derive(Debug, Args)]
+pub struct Resume {
+    /// id of the resume to show, shows all resumes if not given
+    id: Option<i64>,
+    /// list the versions recorded each time the resume was exported, instead of it's contents
+    #[arg(long, requires = "id")]
+    versions: bool,
+}
+
+impl Resume {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        if let (Some(id), true) = (self.id, self.versions) {
+            // versions outlive the resume they were exported from, so don't need it to exist
+            return write_versions(&mut conn, writer, id).await;
+        }
+        let resumes = match self.id {
+            Some(id) => vec![(id, model::Resume::get_one_by_id(&mut conn, id).await?)],
+            None => model::Resume::get_all(&mut conn).await?,
+        };
+
+        for (id, resume) in resumes {
+            write_resume(&mut conn, writer, id, &resume).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write a resume, followed by the ids of the items each of it's sections list & the bullets
+/// it's selected.
+async fn write_resume(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    id: i64,
+    resume: &model::Resume,
+) -> anyhow::Result<()> {
+    let key = Key::Db(id);
+    writeln!(writer, "Resume {id}: {}", resume.name)?;
+
+    if let Some((header, section)) = model::HeaderSection::get_one_for(conn, &key).await? {
+        writeln!(writer, "  header {header}: {}", section.name)?;
+    }
+    if let Some((summary, section)) = model::SummarySection::get_one_for(conn, &key).await? {
+        writeln!(writer, "  summary {summary}: {}", section.content)?;
+    }
+    write_listing::<model::SkillsSection>(conn, writer, &key, "skills").await?;
+    write_listing::<model::EducationSection>(conn, writer, &key, "degrees").await?;
+    write_listing::<model::ExperienceSection>(conn, writer, &key, "jobs").await?;
+    write_listing::<model::ProjectSection>(conn, writer, &key, "projects").await?;
+    write_listing::<model::CertificationSection>(conn, writer, &key, "certifications").await?;
+    write_listing::<model::AwardSection>(conn, writer, &key, "awards").await?;
+    write_listing::<model::PublicationSection>(conn, writer, &key, "publications").await?;
+    write_listing::<model::VolunteerSection>(conn, writer, &key, "volunteering").await?;
+    write_listing::<model::LanguageSection>(conn, writer, &key, "languages").await?;
+
+    let selected = model::SelectedBullet::get_all_for(conn, &key).await?;
+    if !selected.is_empty() {
+        writeln!(writer, "  selected bullets:")?;
+    }
+    for (_, selection) in selected {
+        let item_id = selection.item.db_id()?;
+        let item = model::DescriptionItem::get_one_by_id(conn, item_id).await?;
+        let parent = match item.parent {
+            Parent::Job(key) => format!("Job {}", key.db_id()?),
+            Parent::Project(key) => format!("Project {}", key.db_id()?),
+        };
+        write!(
+            writer,
+            "    Bullet {item_id} of {parent}: {}",
+            selection.text_for(&item)
+        )?;
+        if selection.text.is_some() {
+            write!(writer, " (instead of: {})", item.text)?;
+        } else if let Some(label) = &selection.variant {
+            write!(writer, " ({label} variant)")?;
+        }
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+/// Write when each version of a resume was exported, along with it's hash.
+async fn write_versions(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    id: i64,
+) -> anyhow::Result<()> {
+    writeln!(writer, "Versions of resume {id}:")?;
+    let versions = Version::all_for(conn, id).await?;
+    if versions.is_empty() {
+        writeln!(writer, "  not exported yet")?;
+    }
+    for version in versions {
+        writeln!(
+            writer,
+            "  version {}, exported {}, sha256 {}",
+            version.number,
+            version.exported_at.format("%Y-%m-%d %H:%M:%S UTC"),
+            version.hash
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Write the ids of the items listed in the resume's section of the given type, if it has one.
+async fn write_listing<S: ListedSection>(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    resume: &Key,
+    label: &str,
+) -> anyhow::Result<()> {
+    if let Some((id, section)) = S::get_one_for(conn, resume).await? {
+        let items = section
+            .items()
+            .iter()
+            .map(|key| key.db_id().map(|id| id.to_string()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        writeln!(writer, "  section {id}, {label}: {}", items.join(", "))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use std::io::Write;
+
+use clap::{Args, Subcommand};
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::date_range;
+use crate::model::{self, DbModel, Parent};
+use crate::state::Key;
+
+#[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
+pub struct Skill {
+    #[command(subcommand)]
+    cmd: Option<SkillCommand>,
+    /// name or alias of the skill to show
+    name: Option<String>,
+    /// list every job, project, degree, course & bullet backing the skill
+    #[arg(long, requires = "name")]
+    evidence: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum SkillCommand {
+    ById {
+        id: i64,
+    },
+    Includes {
+        query: String,
+    },
+    /// list every skill, nested under the categories they're grouped in
+    Tree,
+}
+
+impl Skill {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let (id, skill) = match (&self.cmd, &self.name) {
+            (Some(SkillCommand::ById { id }), _) => {
+                (*id, model::Skill::get_one_by_id(&mut conn, *id).await?)
+            }
+            (Some(SkillCommand::Includes { query }), _) => {
+                model::Skill::get_one_includes(&mut conn, query).await?
+            }
+            (Some(SkillCommand::Tree), _) => {
+                let categories = model::SkillCategory::get_all(&mut conn).await?;
+                let skills = model::Skill::get_all(&mut conn).await?;
+
+                return write_tree(writer, &categories, &skills);
+            }
+            (None, Some(name)) => model::Skill::get_one_by_name(&mut conn, name)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("No skill named {name}"))?,
+            (None, None) => anyhow::bail!("Give a skill name or subcommand"),
+        };
+
+        writeln!(writer, "Skill found: {id}: {}", label(&skill))?;
+        if let Some(category) = skill.category {
+            let path = model::SkillCategory::path(&mut conn, category.db_id()?).await?;
+            writeln!(writer, "  category: {}", path.join(" > "))?;
+        }
+        if self.evidence {
+            write_evidence(&mut conn, writer, id, &skill).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write everything the skill was developed through, followed by any other jobs or projects with
+/// bullets mentioning the skill.
+///
+/// Each job or project is followed by it's bullets that mention the skill.
+async fn write_evidence(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    id: i64,
+    skill: &model::Skill,
+) -> anyhow::Result<()> {
+    let developers = model::Developer::all_for(&mut *conn, id).await?;
+    let mentions: Vec<_> = model::DescriptionItem::get_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|(_, item)| item)
+        .filter(|item| skill.is_mentioned_in(&item.text))
+        .collect();
+
+    let mut mentioned_by = Vec::new();
+    for item in &mentions {
+        let developer = match item.parent {
+            Parent::Job(key) => model::Developer::Job(key.db_id()?),
+            Parent::Project(key) => model::Developer::Project(key.db_id()?),
+        };
+        if !developers.contains(&developer) && !mentioned_by.contains(&developer) {
+            mentioned_by.push(developer);
+        }
+    }
+    mentioned_by.sort();
+
+    if developers.is_empty() {
+        writeln!(writer, "  evidence: none")?;
+    } else {
+        writeln!(writer, "  evidence:")?;
+    }
+    for developer in developers {
+        write_developer(&mut *conn, writer, developer, &mentions).await?;
+    }
+    if !mentioned_by.is_empty() {
+        writeln!(writer, "  also mentioned by:")?;
+    }
+    for developer in mentioned_by {
+        write_developer(&mut *conn, writer, developer, &mentions).await?;
+    }
+
+    Ok(())
+}
+
+/// Write a single line describing the developer, followed by any of the given bullets describing
+/// it.
+async fn write_developer(
+    conn: &mut SqliteConnection,
+    writer: &mut impl Write,
+    developer: model::Developer,
+    bullets: &[model::DescriptionItem],
+) -> anyhow::Result<()> {
+    let parent = match developer {
+        model::Developer::Job(id) => {
+            let job = model::Job::get_one_by_id(&mut *conn, id).await?;
+            writeln!(
+                writer,
+                "    Job {id}: {} at {}, {}",
+                job.title,
+                job.employer,
+                date_range(Some(job.start), job.end)
+            )?;
+            Some(Parent::Job(Key::Db(id)))
+        }
+        model::Developer::Project(id) => {
+            let project = model::Project::get_one_by_id(&mut *conn, id).await?;
+            write!(writer, "    Project {id}: {}", project.name)?;
+            if project.start.is_some() || project.end.is_some() {
+                write!(writer, ", {}", date_range(project.start, project.end))?;
+            }
+            writeln!(writer)?;
+            Some(Parent::Project(Key::Db(id)))
+        }
+        model::Developer::Degree(id) => {
+            let degree = model::Degree::get_one_by_id(&mut *conn, id).await?;
+            writeln!(
+                writer,
+                "    Degree {id}: {} in {}",
+                degree.level, degree.major
+            )?;
+            None
+        }
+        model::Developer::Course(id) => {
+            let course = model::Course::get_one_by_id(&mut *conn, id).await?;
+            let degree_id = course.degree.db_id()?;
+            let degree = model::Degree::get_one_by_id(&mut *conn, degree_id).await?;
+            writeln!(
+                writer,
+                "    Course {id}: {}, for Degree {degree_id}: {} in {}",
+                course.name, degree.level, degree.major
+            )?;
+            None
+        }
+    };
+
+    for bullet in bullets.iter().filter(|item| Some(item.parent) == parent) {
+        writeln!(writer, "      - {}", bullet.text)?;
+    }
+
+    Ok(())
+}
+
+/// Write each top level category with everything nested under it, followed by any skills that
+/// aren't in a category.
+fn write_tree(
+    writer: &mut impl Write,
+    categories: &[(i64, model::SkillCategory)],
+    skills: &[(i64, model::Skill)],
+) -> anyhow::Result<()> {
+    write_children(writer, categories, skills, None, 0)?;
+
+    let uncategorized: Vec<_> = skills
+        .iter()
+        .filter(|(_, skill)| skill.category.is_none())
+        .collect();
+    if !uncategorized.is_empty() {
+        writeln!(writer, "Uncategorized")?;
+        for (id, skill) in uncategorized {
+            writeln!(writer, "  Skill {id}: {}", label(skill))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the categories nested directly under `parent` (or the top level ones if `None`), each
+/// followed by it's own skills & subcategories.
+fn write_children(
+    writer: &mut impl Write,
+    categories: &[(i64, model::SkillCategory)],
+    skills: &[(i64, model::Skill)],
+    parent: Option<i64>,
+    depth: usize,
+) -> anyhow::Result<()> {
+    let indent = "  ".repeat(depth);
+    let children = categories
+        .iter()
+        .filter(|(_, category)| category.parent == parent.map(Key::Db));
+
+    for (id, category) in children {
+        writeln!(writer, "{indent}Category {id}: {}", category.name)?;
+        for (skill_id, skill) in skills
+            .iter()
+            .filter(|(_, skill)| skill.category == Some(Key::Db(*id)))
+        {
+            writeln!(writer, "{indent}  Skill {skill_id}: {}", label(skill))?;
+        }
+        write_children(writer, categories, skills, Some(*id), depth + 1)?;
+    }
+
+    Ok(())
+}
+
+/// A skill's name, followed by any aliases it has.
+fn label(skill: &model::Skill) -> String {
+    match skill.aliases.is_empty() {
+        true => skill.name.clone(),
+        false => format!("{} (also {})", skill.name, skill.aliases.join(", ")),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn tree_nests_skills_under_categories() {
+    let categories = vec![
+        (1, model::SkillCategory::new("Languages".into(), None)),
+        (
+            2,
+            model::SkillCategory::new("Systems".into(), Some(Key::Db(1))),
+        ),
+    ];
+    let skills = vec![
+        (1, model::Skill::new("Bash".into(), None)),
+        (2, model::Skill::new("Python".into(), Some(Key::Db(1)))),
+        (3, model::Skill::new("Rust".into(), Some(Key::Db(2)))),
+    ];
+    let mut out = Vec::new();
+
+    write_tree(&mut out, &categories, &skills).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "Category 1: Languages
+  Skill 2: Python
+  Category 2: Systems
+    Skill 3: Rust
+Uncategorized
+  Skill 1: Bash
+"
+    );
+}
@@ -0,0 +1,48 @@
+use std::io::Write;
+
+use clap::Args;
+use sqlx::SqlitePool;
+
+use super::date_range;
+use crate::model::{self, DbModel};
+
+#[derive(Debug, Args)]
+pub struct Volunteer {
+    /// id of the volunteer work to show, shows all volunteer work if not given
+    id: Option<i64>,
+}
+
+impl Volunteer {
+    pub async fn handle(&self, pool: &SqlitePool, writer: &mut impl Write) -> anyhow::Result<()> {
+        let mut conn = pool.acquire().await?;
+        let volunteering = match self.id {
+            Some(id) => vec![(id, model::Volunteer::get_one_by_id(&mut conn, id).await?)],
+            None => model::Volunteer::get_all(&mut conn).await?,
+        };
+
+        for (id, volunteer) in volunteering {
+            write_volunteer(writer, id, &volunteer)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_volunteer(
+    writer: &mut impl Write,
+    id: i64,
+    volunteer: &model::Volunteer,
+) -> anyhow::Result<()> {
+    writeln!(
+        writer,
+        "Volunteer {id}: {} at {}, {}",
+        volunteer.role,
+        volunteer.organization,
+        date_range(Some(volunteer.start), volunteer.end)
+    )?;
+    if let Some(description) = &volunteer.description {
+        writeln!(writer, "  {description}")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::io::Write;
+
+use clap::Args;
+
+use crate::state;
+use crate::store::Store;
+
+/// Show changes that haven't been saved yet
+#[derive(Debug, Args)]
+pub struct Status {}
+
+impl Status {
+    pub fn handle(&self, store: &Store, writer: &mut impl Write) -> anyhow::Result<()> {
+        write_report(store, writer)
+    }
+}
+
+/// Write every change not yet saved, like `git status`, grouped by type, e.g. for a dry run to
+/// show what it would've saved.
+pub fn write_report(store: &Store, writer: &mut impl Write) -> anyhow::Result<()> {
+    let status = store.status();
+    if status.is_empty() {
+        writeln!(writer, "Nothing to save")?;
+        return Ok(());
+    }
+
+    writeln!(writer, "Changes not saved:")?;
+    let mut shown = None;
+    for (model, key, status) in status {
+        if shown != Some(model) {
+            writeln!(writer, "  {model}:")?;
+            shown = Some(model);
+        }
+        let status = match status {
+            state::Status::Added => "added:   ",
+            state::Status::Modified => "modified:",
+            state::Status::Deleted => "deleted: ",
+        };
+        writeln!(writer, "    {status} {key}")?;
+    }
+
+    Ok(())
+}
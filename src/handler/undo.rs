@@ -0,0 +1,35 @@
+use std::io::Write;
+
+use clap::Args;
+
+use crate::store::Store;
+
+/// Undo the most recent command that changed anything
+#[derive(Debug, Args)]
+pub struct Undo {}
+
+impl Undo {
+    pub async fn handle(&self, store: &mut Store, writer: &mut impl Write) -> anyhow::Result<()> {
+        match store.undo().await? {
+            None => writeln!(writer, "Nothing left to undo")?,
+            Some(undone) => writeln!(writer, "Undid: {undone}")?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Redo the most recently undone command
+#[derive(Debug, Args)]
+pub struct Redo {}
+
+impl Redo {
+    pub async fn handle(&self, store: &mut Store, writer: &mut impl Write) -> anyhow::Result<()> {
+        match store.redo().await? {
+            None => writeln!(writer, "Nothing left to redo")?,
+            Some(redone) => writeln!(writer, "Redid: {redone}")?,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,813 @@
+//! A record of every change saved to the db, so saves can be undone & redone later.
+//!
+//! Each save is recorded as a batch of entries, one per row it changed, holding snapshots of that
+//! row from before & after the change. Undoing a batch puts each row back the way it was before,
+//! newest change first; redoing it puts them back the way they were after, oldest first. The rows
+//! an undo or redo puts back are recorded too, though they can't be undone or redone themselves.
+//!
+//! Every change is also logged to a journal in the form it was made, e.g. an edit to a job, along
+//! with when & during which session it was saved. Row changes point back at the journalled change
+//! they were part of, so the journal can show what each change did.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    marker::PhantomData,
+};
+
+use anyhow::bail;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::SqliteConnection;
+
+use crate::{
+    model::{self, DbModel},
+    store::{Conflict, Conflicts},
+};
+
+/// A change as the journal logs it, in the form it was made.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "kebab-case")]
+pub enum Logged {
+    Add {
+        model: String,
+        id: i64,
+        value: Value,
+    },
+    Replace {
+        model: String,
+        id: i64,
+        value: Value,
+    },
+    Delete {
+        model: String,
+        id: i64,
+    },
+    Undo {
+        changes: usize,
+    },
+    Redo {
+        changes: usize,
+    },
+}
+
+/// Logged changes display as what was done to which row, e.g. `deleted Job 1`.
+impl fmt::Display for Logged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Logged::Add { model, id, .. } => write!(f, "added {model} {id}"),
+            Logged::Replace { model, id, .. } => write!(f, "edited {model} {id}"),
+            Logged::Delete { model, id } => write!(f, "deleted {model} {id}"),
+            Logged::Undo { changes } => write!(f, "undid {changes} change(s)"),
+            Logged::Redo { changes } => write!(f, "redid {changes} change(s)"),
+        }
+    }
+}
+
+/// A save undone or redone, as the changes it logged to the journal, i.e. what one command did.
+#[derive(Debug, PartialEq)]
+pub struct Save {
+    /// when the save was made, unknown for saves made before the journal was kept
+    pub recorded_at: Option<DateTime<Utc>>,
+    pub changes: Vec<Logged>,
+    /// how many rows the save changed, including any it's changes cascaded to
+    pub rows: usize,
+}
+
+impl fmt::Display for Save {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(recorded_at) = self.recorded_at else {
+            return write!(f, "a save changing {} row(s)", self.rows);
+        };
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{change}")?;
+        }
+
+        write!(f, " ({})", recorded_at.format("%Y-%m-%d %H:%M:%S UTC"))
+    }
+}
+
+/// A save being recorded, numbering the changes recorded during it.
+#[derive(Debug)]
+pub struct Batch {
+    id: i64,
+    position: i64,
+    session: String,
+    /// the journalled change rows recorded now are part of
+    journal: Option<i64>,
+    /// whether rows recorded are put back by an undo or redo, rather than saved
+    revert: bool,
+}
+
+impl Batch {
+    /// Start recording a new save made during the given session. Anything undone before it can
+    /// no longer be redone.
+    pub async fn start(conn: &mut SqliteConnection, session: &str) -> anyhow::Result<Self> {
+        sqlx::query("UPDATE History SET abandoned=1 WHERE undone=1;")
+            .execute(&mut *conn)
+            .await?;
+        let id = sqlx::query_scalar("SELECT COALESCE(MAX(batch), 0) + 1 FROM History;")
+            .fetch_one(conn)
+            .await?;
+
+        Ok(Self {
+            id,
+            position: 0,
+            session: session.into(),
+            journal: None,
+            revert: false,
+        })
+    }
+
+    /// Start recording the rows an undo or redo puts back during the given session, which can't
+    /// be undone or redone themselves.
+    async fn revert(conn: &mut SqliteConnection, session: &str) -> anyhow::Result<Self> {
+        let id = sqlx::query_scalar("SELECT COALESCE(MAX(batch), 0) + 1 FROM History;")
+            .fetch_one(&mut *conn)
+            .await?;
+
+        Ok(Self {
+            id,
+            position: 0,
+            session: session.into(),
+            journal: None,
+            revert: true,
+        })
+    }
+
+    /// Log a change to the journal. Rows recorded after this are recorded as part of it.
+    pub async fn log(
+        &mut self,
+        conn: &mut SqliteConnection,
+        change: &Logged,
+    ) -> anyhow::Result<()> {
+        self.journal = Some(log(conn, &self.session, change).await?);
+
+        Ok(())
+    }
+
+    /// Record a change to the `M` stored under the given id, from one snapshot to the other.
+    pub async fn record<M>(
+        &mut self,
+        conn: &mut SqliteConnection,
+        id: i64,
+        before: Option<&M>,
+        after: Option<&M>,
+    ) -> anyhow::Result<()>
+    where
+        M: DbModel + Serialize,
+    {
+        let before = before.map(serde_json::to_string).transpose()?;
+        let after = after.map(serde_json::to_string).transpose()?;
+
+        self.record_raw(conn, M::TABLE, id, before.as_deref(), after.as_deref())
+            .await
+    }
+
+    /// Record every row that differs between two snapshots of the db.
+    ///
+    /// Changed rows are recorded before removed ones, & rows are removed children first, so
+    /// undoing puts parents back before their children & only then points anything back at them.
+    pub async fn record_diff(
+        &mut self,
+        conn: &mut SqliteConnection,
+        before: &Snapshot,
+        after: &Snapshot,
+    ) -> anyhow::Result<()> {
+        let tables = || before.0.iter().zip(&after.0);
+        for ((table, before), (_, after)) in tables() {
+            for (id, old) in before {
+                match after.get(id) {
+                    Some(new) if new != old => {
+                        self.record_raw(conn, table, *id, Some(old), Some(new))
+                            .await?
+                    }
+                    _ => (),
+                }
+            }
+            for (id, new) in after {
+                if !before.contains_key(id) {
+                    self.record_raw(conn, table, *id, None, Some(new)).await?;
+                }
+            }
+        }
+        for ((table, before), (_, after)) in tables() {
+            for (id, old) in before {
+                if !after.contains_key(id) {
+                    self.record_raw(conn, table, *id, Some(old), None).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Put a row back to the given snapshot, removing it if there isn't one, & record the change.
+    async fn put(
+        &mut self,
+        conn: &mut SqliteConnection,
+        model: &str,
+        id: i64,
+        snapshot: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let table = table(model)?;
+        let current = table.snapshot(conn, id).await?;
+        table.put(conn, id, snapshot).await?;
+
+        self.record_raw(conn, model, id, current.as_deref(), snapshot)
+            .await
+    }
+
+    async fn record_raw(
+        &mut self,
+        conn: &mut SqliteConnection,
+        table: &str,
+        id: i64,
+        before: Option<&str>,
+        after: Option<&str>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO History (batch, position, model, row_id, before, after, journal_id, revert)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8);",
+        )
+        .bind(self.id)
+        .bind(self.position)
+        .bind(table)
+        .bind(id)
+        .bind(before)
+        .bind(after)
+        .bind(self.journal)
+        .bind(self.revert)
+        .execute(conn)
+        .await?;
+        self.position += 1;
+
+        Ok(())
+    }
+}
+
+/// Rows of the db, as the json history records them as, for finding everything a change touched
+/// when it can cascade, e.g. deleting a row.
+#[derive(Debug)]
+pub struct Snapshot(Vec<(&'static str, BTreeMap<i64, String>)>);
+
+/// The ids of some of the rows in each table.
+type Scope = BTreeMap<String, BTreeSet<i64>>;
+
+impl Snapshot {
+    /// Snapshot every row deleting a row of `table` can reach, i.e. the row itself, the rows the
+    /// db cascades the delete to, the rows it nulls out, & the rows any of those are a part of.
+    pub async fn reachable(
+        conn: &mut SqliteConnection,
+        table: &str,
+        id: i64,
+    ) -> anyhow::Result<Self> {
+        // an empty scope reads nothing, it just lists the tables that hold models
+        let models = Self::scoped(conn, &Scope::new()).await?;
+        let models: BTreeSet<&str> = models.0.iter().map(|(table, _)| *table).collect();
+        let keys = foreign_keys(conn).await?;
+
+        let mut scope = Scope::new();
+        let mut deleted = vec![(table.to_string(), id)];
+        let mut touched = Vec::new();
+        while let Some((table, id)) = deleted.pop() {
+            if !scope.entry(table.clone()).or_default().insert(id) {
+                continue;
+            }
+            for key in keys.iter().filter(|key| key.parent == table) {
+                let children: Vec<i64> = sqlx::query_scalar(&format!(
+                    "SELECT rowid FROM {} WHERE {}=$1;",
+                    key.table, key.column
+                ))
+                .bind(id)
+                .fetch_all(&mut *conn)
+                .await?;
+                let reached = children.into_iter().map(|child| (key.table.clone(), child));
+                if key.cascades {
+                    deleted.extend(reached);
+                } else {
+                    touched.extend(reached);
+                }
+            }
+            touched.push((table, id));
+        }
+
+        // rows w/o a model of their own, e.g. links between skills & jobs, are part of the rows
+        // they point to, so changing them changes those rows too
+        while let Some((table, id)) = touched.pop() {
+            scope.entry(table.clone()).or_default().insert(id);
+            if models.contains(table.as_str()) {
+                continue;
+            }
+            for key in keys.iter().filter(|key| key.table == table) {
+                let parent: Option<i64> = sqlx::query_scalar(&format!(
+                    "SELECT {} FROM {} WHERE rowid=$1;",
+                    key.column, key.table
+                ))
+                .bind(id)
+                .fetch_optional(&mut *conn)
+                .await?
+                .flatten();
+                touched.extend(parent.map(|parent| (key.parent.clone(), parent)));
+            }
+        }
+
+        Self::scoped(conn, &scope).await
+    }
+
+    /// Snapshot the same rows again, e.g. to see what became of them after a change.
+    pub async fn again(&self, conn: &mut SqliteConnection) -> anyhow::Result<Self> {
+        let scope = self
+            .0
+            .iter()
+            .map(|(table, rows)| (table.to_string(), rows.keys().copied().collect()))
+            .collect();
+
+        Self::scoped(conn, &scope).await
+    }
+
+    /// Snapshot the rows in `scope` that still exist, tables holding children before those
+    /// holding their parents.
+    async fn scoped(conn: &mut SqliteConnection, scope: &Scope) -> anyhow::Result<Self> {
+        Ok(Self(vec![
+            rows::<model::SelectedBullet>(conn, scope).await?,
+            rows::<model::Contact>(conn, scope).await?,
+            rows::<model::SummarySection>(conn, scope).await?,
+            rows::<model::SkillsSection>(conn, scope).await?,
+            rows::<model::EducationSection>(conn, scope).await?,
+            rows::<model::ExperienceSection>(conn, scope).await?,
+            rows::<model::ProjectSection>(conn, scope).await?,
+            rows::<model::CertificationSection>(conn, scope).await?,
+            rows::<model::AwardSection>(conn, scope).await?,
+            rows::<model::PublicationSection>(conn, scope).await?,
+            rows::<model::VolunteerSection>(conn, scope).await?,
+            rows::<model::LanguageSection>(conn, scope).await?,
+            rows::<model::HeaderSection>(conn, scope).await?,
+            rows::<model::DescriptionItem>(conn, scope).await?,
+            rows::<model::Course>(conn, scope).await?,
+            rows::<model::Job>(conn, scope).await?,
+            rows::<model::Project>(conn, scope).await?,
+            rows::<model::Degree>(conn, scope).await?,
+            rows::<model::Certification>(conn, scope).await?,
+            rows::<model::Award>(conn, scope).await?,
+            rows::<model::Publication>(conn, scope).await?,
+            rows::<model::Volunteer>(conn, scope).await?,
+            rows::<model::Language>(conn, scope).await?,
+            rows::<model::Skill>(conn, scope).await?,
+            rows::<model::SkillCategory>(conn, scope).await?,
+            rows::<model::Resume>(conn, scope).await?,
+        ]))
+    }
+}
+
+async fn rows<M>(
+    conn: &mut SqliteConnection,
+    scope: &Scope,
+) -> anyhow::Result<(&'static str, BTreeMap<i64, String>)>
+where
+    M: DbModel + Serialize,
+{
+    let mut rows = BTreeMap::new();
+    for &id in scope.get(M::TABLE).into_iter().flatten() {
+        if exists(conn, M::TABLE, id).await? {
+            let value = M::get_one_by_id(conn, id).await?;
+            rows.insert(id, serde_json::to_string(&value)?);
+        }
+    }
+
+    Ok((M::TABLE, rows))
+}
+
+/// A column of `table` pointing at rows of `parent`, & whether deleting those rows deletes the
+/// rows pointing at them too, or just nulls them out.
+struct ForeignKey {
+    table: String,
+    column: String,
+    parent: String,
+    cascades: bool,
+}
+
+async fn foreign_keys(conn: &mut SqliteConnection) -> anyhow::Result<Vec<ForeignKey>> {
+    let keys: Vec<(String, String, String, String)> = sqlx::query_as(
+        "SELECT m.name, k.\"from\", k.\"table\", k.on_delete
+         FROM sqlite_master AS m JOIN pragma_foreign_key_list(m.name) AS k
+         WHERE m.type='table' AND k.on_delete IN ('CASCADE', 'SET NULL');",
+    )
+    .fetch_all(conn)
+    .await?;
+
+    Ok(keys
+        .into_iter()
+        .map(|(table, column, parent, on_delete)| ForeignKey {
+            table,
+            column,
+            parent,
+            cascades: on_delete == "CASCADE",
+        })
+        .collect())
+}
+
+type Entry = (String, i64, Option<String>, Option<String>);
+
+/// Undo the most recent save that hasn't been undone yet, returning it, or `None` if there's
+/// nothing left to undo.
+///
+/// Fails with the rows anyone's changed since the save, as undoing it would overwrite their
+/// changes, unless rebasing, in which case undoing wins.
+pub async fn undo(
+    conn: &mut SqliteConnection,
+    session: &str,
+    rebase: bool,
+) -> anyhow::Result<Option<Save>> {
+    let batch: Option<i64> =
+        sqlx::query_scalar("SELECT MAX(batch) FROM History WHERE undone=0 AND revert=0;")
+            .fetch_one(&mut *conn)
+            .await?;
+    let Some(batch) = batch else { return Ok(None) };
+    defer_foreign_keys(conn).await?;
+
+    let entries: Vec<Entry> = sqlx::query_as(
+        "SELECT model, row_id, before, after FROM History
+         WHERE batch=$1 ORDER BY position DESC;",
+    )
+    .bind(batch)
+    .fetch_all(&mut *conn)
+    .await?;
+    if !rebase {
+        // newest first, so each row should be as the last change to it left it
+        let expected = entries
+            .iter()
+            .map(|(model, id, _, after)| (model, *id, after));
+        unchanged_since(conn, expected).await?;
+    }
+    let changes = entries.len();
+    let mut reverting = Batch::revert(conn, session).await?;
+    reverting.log(conn, &Logged::Undo { changes }).await?;
+    for (model, id, before, _) in &entries {
+        reverting.put(conn, model, *id, before.as_deref()).await?;
+    }
+    mark(conn, batch, true).await?;
+
+    saved(conn, batch, changes).await.map(Some)
+}
+
+/// Redo the oldest save still undone, i.e. the last one undone, returning it, or `None` if there's
+/// nothing left to redo.
+///
+/// Like [`undo`], fails with the rows anyone's changed since the undo, unless rebasing.
+pub async fn redo(
+    conn: &mut SqliteConnection,
+    session: &str,
+    rebase: bool,
+) -> anyhow::Result<Option<Save>> {
+    let batch: Option<i64> =
+        sqlx::query_scalar("SELECT MIN(batch) FROM History WHERE undone=1 AND abandoned=0;")
+            .fetch_one(&mut *conn)
+            .await?;
+    let Some(batch) = batch else { return Ok(None) };
+    defer_foreign_keys(conn).await?;
+
+    let entries: Vec<Entry> = sqlx::query_as(
+        "SELECT model, row_id, before, after FROM History
+         WHERE batch=$1 ORDER BY position ASC;",
+    )
+    .bind(batch)
+    .fetch_all(&mut *conn)
+    .await?;
+    if !rebase {
+        // oldest first, so each row should be as it was before the first change to it
+        let expected = entries
+            .iter()
+            .map(|(model, id, before, _)| (model, *id, before));
+        unchanged_since(conn, expected).await?;
+    }
+    let changes = entries.len();
+    let mut reverting = Batch::revert(conn, session).await?;
+    reverting.log(conn, &Logged::Redo { changes }).await?;
+    for (model, id, _, after) in &entries {
+        reverting.put(conn, model, *id, after.as_deref()).await?;
+    }
+    mark(conn, batch, false).await?;
+
+    saved(conn, batch, changes).await.map(Some)
+}
+
+/// Fail with every row that isn't as the first snapshot given of it has it.
+async fn unchanged_since<'e>(
+    conn: &mut SqliteConnection,
+    expected: impl Iterator<Item = (&'e String, i64, &'e Option<String>)>,
+) -> anyhow::Result<()> {
+    let mut checked = BTreeSet::new();
+    let mut conflicts = Vec::new();
+    for (model, id, snapshot) in expected {
+        if !checked.insert((model, id)) {
+            continue;
+        }
+        let table = table(model)?;
+        if !table.matches(conn, id, snapshot.as_deref()).await? {
+            conflicts.push(Conflict {
+                model: table.name(),
+                id,
+                deleted: !exists(conn, table.name(), id).await?,
+            });
+        }
+    }
+
+    match conflicts.is_empty() {
+        true => Ok(()),
+        false => Err(Conflicts(conflicts).into()),
+    }
+}
+
+/// Read the changes a batch logged to the journal, given how many rows it changed.
+async fn saved(conn: &mut SqliteConnection, batch: i64, rows: usize) -> anyhow::Result<Save> {
+    let logged: Vec<(DateTime<Utc>, String)> = sqlx::query_as(
+        "SELECT recorded_at, change FROM Journal
+         WHERE id IN (SELECT journal_id FROM History WHERE batch=$1)
+         ORDER BY id;",
+    )
+    .bind(batch)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(Save {
+        recorded_at: logged.first().map(|(recorded_at, _)| *recorded_at),
+        changes: logged
+            .iter()
+            .map(|(_, change)| serde_json::from_str(change))
+            .collect::<Result<_, _>>()?,
+        rows,
+    })
+}
+
+/// How many times the db has been written to, for telling whether anyone else has written to it
+/// since it was read.
+pub async fn generation(conn: &mut SqliteConnection) -> anyhow::Result<i64> {
+    sqlx::query_scalar("SELECT value FROM Generation;")
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+}
+
+/// Count a write to the db, returning the generation it was written over.
+///
+/// Run first thing in a transaction, this takes the db's write lock, so no one else can write to it
+/// until the transaction ends, & the generation returned stays current until then too.
+pub async fn bump_generation(conn: &mut SqliteConnection) -> anyhow::Result<i64> {
+    sqlx::query_scalar("UPDATE Generation SET value = value + 1 RETURNING value - 1;")
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+}
+
+/// Log a change to the journal, returning the id of it's journal entry.
+async fn log(conn: &mut SqliteConnection, session: &str, change: &Logged) -> anyhow::Result<i64> {
+    sqlx::query_scalar(
+        "INSERT INTO Journal (session, recorded_at, change) VALUES ($1, $2, $3) RETURNING id;",
+    )
+    .bind(session)
+    .bind(Utc::now())
+    .bind(serde_json::to_string(change)?)
+    .fetch_one(conn)
+    .await
+    .map_err(|e| e.into())
+}
+
+/// A change logged to the journal, along with the changes it made to rows.
+#[derive(Debug)]
+pub struct JournalEntry {
+    pub id: i64,
+    pub session: String,
+    pub recorded_at: DateTime<Utc>,
+    pub change: Logged,
+    /// whether the change has since been undone
+    pub undone: bool,
+    pub rows: Vec<RowChange>,
+}
+
+/// A row changed by a journalled change, with snapshots from before & after.
+#[derive(Debug, PartialEq)]
+pub struct RowChange {
+    pub model: String,
+    pub id: i64,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+type JournalRow = (i64, String, DateTime<Utc>, String);
+type RowChangeRow = (String, i64, Option<String>, Option<String>, bool);
+
+/// Read the journal, oldest change first.
+///
+/// Given a row as a model & id, only changes to that row are read, e.g. every edit to a bullet.
+pub async fn journal(
+    conn: &mut SqliteConnection,
+    item: Option<(&str, i64)>,
+) -> anyhow::Result<Vec<JournalEntry>> {
+    let logged: Vec<JournalRow> =
+        sqlx::query_as("SELECT id, session, recorded_at, change FROM Journal ORDER BY id;")
+            .fetch_all(&mut *conn)
+            .await?;
+
+    let mut entries = Vec::new();
+    for (id, session, recorded_at, change) in logged {
+        let rows: Vec<RowChangeRow> = sqlx::query_as(
+            "SELECT model, row_id, before, after, undone FROM History
+             WHERE journal_id=$1 ORDER BY position;",
+        )
+        .bind(id)
+        .fetch_all(&mut *conn)
+        .await?;
+        let undone = rows.iter().any(|(.., undone)| *undone);
+        let rows = rows
+            .into_iter()
+            .filter(|(model, row_id, ..)| match item {
+                Some((item_model, item_id)) => {
+                    model.eq_ignore_ascii_case(item_model) && *row_id == item_id
+                }
+                None => true,
+            })
+            .map(|(model, id, before, after, _)| {
+                Ok(RowChange {
+                    model,
+                    id,
+                    before: before.as_deref().map(serde_json::from_str).transpose()?,
+                    after: after.as_deref().map(serde_json::from_str).transpose()?,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if item.is_some() && rows.is_empty() {
+            continue;
+        }
+
+        entries.push(JournalEntry {
+            id,
+            session,
+            recorded_at,
+            change: serde_json::from_str(&change)?,
+            undone,
+            rows,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Only check foreign keys once the transaction commits, as rows put back in one go can refer to
+/// each other in any order, e.g. nested skill categories.
+async fn defer_foreign_keys(conn: &mut SqliteConnection) -> anyhow::Result<()> {
+    sqlx::query("PRAGMA defer_foreign_keys = ON;")
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+async fn mark(conn: &mut SqliteConnection, batch: i64, undone: bool) -> anyhow::Result<()> {
+    sqlx::query("UPDATE History SET undone=$1 WHERE batch=$2;")
+        .bind(undone)
+        .bind(batch)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// A model's table, for reading & writing the rows history refers to by the name of the table.
+#[async_trait]
+trait Rows: Sync {
+    fn name(&self) -> &'static str;
+
+    /// The row as it is right now, if it's there.
+    async fn snapshot(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+    ) -> anyhow::Result<Option<String>>;
+
+    /// Whether the row is as the given snapshot has it, i.e. gone if there isn't one.
+    async fn matches(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+        snapshot: Option<&str>,
+    ) -> anyhow::Result<bool>;
+
+    /// Put the row back to the given snapshot, removing it if there isn't one.
+    async fn put(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+        snapshot: Option<&str>,
+    ) -> anyhow::Result<()>;
+}
+
+struct Table<M>(PhantomData<fn() -> M>);
+
+impl<M> Table<M> {
+    const ROWS: Self = Self(PhantomData);
+}
+
+#[async_trait]
+impl<M> Rows for Table<M>
+where
+    M: DbModel + Serialize + DeserializeOwned + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        M::TABLE
+    }
+
+    async fn snapshot(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+    ) -> anyhow::Result<Option<String>> {
+        let current = model::get_if_saved::<M>(conn, id).await?;
+
+        Ok(current
+            .map(|current| serde_json::to_string(&current))
+            .transpose()?)
+    }
+
+    async fn matches(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+        snapshot: Option<&str>,
+    ) -> anyhow::Result<bool> {
+        // compared as json, the way it was snapshotted
+        let current = model::get_if_saved::<M>(conn, id)
+            .await?
+            .map(|current| serde_json::to_value(current))
+            .transpose()?;
+        let snapshot: Option<Value> = snapshot.map(serde_json::from_str).transpose()?;
+
+        Ok(current == snapshot)
+    }
+
+    async fn put(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+        snapshot: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let Some(snapshot) = snapshot else {
+            return M::delete(conn, id).await;
+        };
+        let value: M = serde_json::from_str(snapshot)?;
+        if exists(conn, M::TABLE, id).await? {
+            value.update(conn, id).await
+        } else {
+            value.insert(conn, Some(id)).await.map(|_| ())
+        }
+    }
+}
+
+/// The table history refers to by the given name.
+fn table(name: &str) -> anyhow::Result<&'static dyn Rows> {
+    Ok(match name {
+        model::Resume::TABLE => &Table::<model::Resume>::ROWS,
+        model::Contact::TABLE => &Table::<model::Contact>::ROWS,
+        model::Skill::TABLE => &Table::<model::Skill>::ROWS,
+        model::Job::TABLE => &Table::<model::Job>::ROWS,
+        model::DescriptionItem::TABLE => &Table::<model::DescriptionItem>::ROWS,
+        model::Degree::TABLE => &Table::<model::Degree>::ROWS,
+        model::Course::TABLE => &Table::<model::Course>::ROWS,
+        model::Project::TABLE => &Table::<model::Project>::ROWS,
+        model::HeaderSection::TABLE => &Table::<model::HeaderSection>::ROWS,
+        model::SkillCategory::TABLE => &Table::<model::SkillCategory>::ROWS,
+        model::Certification::TABLE => &Table::<model::Certification>::ROWS,
+        model::Award::TABLE => &Table::<model::Award>::ROWS,
+        model::Publication::TABLE => &Table::<model::Publication>::ROWS,
+        model::Volunteer::TABLE => &Table::<model::Volunteer>::ROWS,
+        model::Language::TABLE => &Table::<model::Language>::ROWS,
+        model::SummarySection::TABLE => &Table::<model::SummarySection>::ROWS,
+        model::SkillsSection::TABLE => &Table::<model::SkillsSection>::ROWS,
+        model::EducationSection::TABLE => &Table::<model::EducationSection>::ROWS,
+        model::ExperienceSection::TABLE => &Table::<model::ExperienceSection>::ROWS,
+        model::ProjectSection::TABLE => &Table::<model::ProjectSection>::ROWS,
+        model::CertificationSection::TABLE => &Table::<model::CertificationSection>::ROWS,
+        model::AwardSection::TABLE => &Table::<model::AwardSection>::ROWS,
+        model::PublicationSection::TABLE => &Table::<model::PublicationSection>::ROWS,
+        model::VolunteerSection::TABLE => &Table::<model::VolunteerSection>::ROWS,
+        model::LanguageSection::TABLE => &Table::<model::LanguageSection>::ROWS,
+        model::SelectedBullet::TABLE => &Table::<model::SelectedBullet>::ROWS,
+        _ => bail!("History refers to unknown table {name}"),
+    })
+}
+
+/// Whether the given table has a row with the given id.
+async fn exists(conn: &mut SqliteConnection, table: &str, id: i64) -> anyhow::Result<bool> {
+    let exists = sqlx::query_scalar(&format!(
+        "SELECT EXISTS (SELECT 1 FROM {table} WHERE id=$1);"
+    ))
+    .bind(id)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(exists)
+}
@@ -1,17 +1,23 @@
+// lets code derived within this crate refer to it by name, as it would from any other
+extern crate self as res_gen;
+
 use std::{env, io::Write};
 
 use clap::{Parser, Subcommand, ValueEnum};
 use log::{debug, info};
 use sqlx::SqlitePool;
+use state::KeyMap;
 use store::Store;
 
 mod changelist;
+mod export;
 mod handler;
+mod history;
 mod logging;
-mod model;
+pub mod model;
 mod state;
 mod store;
-mod ttuple;
+pub mod ttuple;
 
 #[derive(Debug, Parser)]
 /// A Resume data storage & generation tool.
@@ -28,12 +34,26 @@ pub struct Args {
     /// set output verbosity
     #[arg(short, long, value_enum)]
     verbose: Option<Verbosity>,
+    /// run the command & show the changes it would make, without saving them
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// save changes on top of any saved by someone else since they were made, & undo or redo
+    /// saves over any changes since, instead of failing when they conflict
+    #[arg(long, global = true)]
+    rebase: bool,
 }
 
 #[derive(Debug, Subcommand)]
 pub enum Command {
     Add(handler::Add),
-    // Show(handler::Show),
+    Delete(handler::Delete),
+    Edit(handler::Edit),
+    Export(handler::Export),
+    History(handler::History),
+    Show(handler::Show),
+    Status(handler::Status),
+    Undo(handler::Undo),
+    Redo(handler::Redo),
 }
 
 /// Obj for holding active db pool, cli command given on exec, and necessary configuration
@@ -59,6 +79,8 @@ impl App {
                 Some(v) => v,
                 None => Verbosity::Error,
             },
+            dry_run: args.dry_run,
+            rebase: args.rebase,
         };
         // setup logging
         logging::initialize(&config.verbose)?;
@@ -67,21 +89,68 @@ impl App {
         let pool = SqlitePool::connect(&config.dburl).await?;
         debug!("DB pool connected.");
         // make sure db is up to date
-        sqlx::migrate!().run(&pool).await?;
+        model::migrate(&pool).await?;
         debug!("DB schema up to date.");
         // init data store
-        let store = Store::new(pool);
+        let store = Store::open(pool).await?.rebase(config.rebase);
 
         Ok(Self { cmd, config, store })
     }
 
     /// Run app w/ command parsed from args & attach output to given write stream
     pub async fn run(mut self, writer: &mut impl Write) -> anyhow::Result<()> {
-        debug!("Executing command {:#?}.", self.cmd);
-        match self.cmd {
-            Command::Add(add) => add.handle(&mut self.store, writer).await.map(|_| ()),
-            // Command::Show(show) => show.handle(&self.pool, writer).await,
+        debug!("Executing command {:#?} w/ {:#?}.", self.cmd, self.config);
+        if self.config.dry_run && matches!(self.cmd, Command::Undo(_) | Command::Redo(_)) {
+            // undoing saved changes writes straight to the db
+            anyhow::bail!("Undo & redo can't be run as a dry run");
+        }
+        let outcome = match self.cmd {
+            Command::Add(add) => Some(add.handle(&mut self.store).await?),
+            Command::Delete(delete) => Some(delete.handle(&mut self.store).await?),
+            Command::Edit(edit) => Some(edit.handle(&mut self.store).await?),
+            Command::Export(export) => {
+                export
+                    .handle(self.store.pool(), self.config.dry_run, writer)
+                    .await?;
+                None
+            }
+            Command::History(history) => {
+                history.handle(self.store.pool(), writer).await?;
+                None
+            }
+            Command::Show(show) => {
+                show.handle(self.store.pool(), writer).await?;
+                None
+            }
+            Command::Status(status) => {
+                status.handle(&self.store, writer)?;
+                None
+            }
+            Command::Undo(undo) => {
+                undo.handle(&mut self.store, writer).await?;
+                None
+            }
+            Command::Redo(redo) => {
+                redo.handle(&mut self.store, writer).await?;
+                None
+            }
+        };
+        if self.config.dry_run {
+            if let Some(outcome) = outcome {
+                outcome.write(&KeyMap::new(), writer)?;
+            }
+            handler::status::write_report(&self.store, writer)?;
+            return Ok(());
         }
+        // only save once the command has succeeded, leaving the db untouched otherwise
+        let keys = self.store.save().await?;
+        debug!("Changes saved, {keys:#?}.");
+        // new values only have an id to report now they're saved
+        if let Some(outcome) = outcome {
+            outcome.write(&keys, writer)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -92,6 +161,10 @@ pub struct AppConfig {
     dburl: String,
     /// Adjust output verbosity, defaults to only output errors
     verbose: Verbosity,
+    /// Show the changes a command makes instead of saving them
+    dry_run: bool,
+    /// Save changes over conflicting ones saved since they were made
+    rebase: bool,
 }
 
 #[derive(Clone, Debug, ValueEnum)]
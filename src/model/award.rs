@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Award {
+    pub name: String,
+    /// Who gave the award
+    pub issuer: Option<String>,
+    pub awarded: Option<NaiveDate>,
+    pub description: Option<String>,
+}
+
+impl Award {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            issuer: None,
+            awarded: None,
+            description: None,
+        }
+    }
+}
+
+type Row = (String, Option<String>, Option<NaiveDate>, Option<String>);
+
+#[async_trait]
+impl DbModel for Award {
+    const TABLE: &'static str = "Award";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO Award (id, name, issuer, award_date, description)
+             VALUES ($1, $2, $3, $4, $5) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.name)
+        .bind(&self.issuer)
+        .bind(self.awarded)
+        .bind(&self.description)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query(
+            "UPDATE Award SET name=$1, issuer=$2, award_date=$3, description=$4 WHERE id=$5;",
+        )
+        .bind(&self.name)
+        .bind(&self.issuer)
+        .bind(self.awarded)
+        .bind(&self.description)
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+        updated_one(result, "Award", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (name, issuer, awarded, description): Row =
+            sqlx::query_as("SELECT name, issuer, award_date, description FROM Award WHERE id=$1;")
+                .bind(id)
+                .fetch_one(conn)
+                .await?;
+
+        Ok(Self {
+            name,
+            issuer,
+            awarded,
+            description,
+        })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let ids: Vec<i64> =
+            sqlx::query_scalar("SELECT id FROM Award ORDER BY award_date DESC, id;")
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut awards = Vec::with_capacity(ids.len());
+        for id in ids {
+            awards.push((id, Self::get_one_by_id(&mut *conn, id).await?));
+        }
+
+        Ok(awards)
+    }
+}
@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Certification {
+    pub name: String,
+    /// Who granted the certification
+    pub issuer: String,
+    pub issued: NaiveDate,
+    /// When the certification lapses, if it does
+    pub expires: Option<NaiveDate>,
+    /// Id a credential can be verified with
+    pub credential_id: Option<String>,
+}
+
+impl Certification {
+    pub fn new(name: String, issuer: String, issued: NaiveDate) -> Self {
+        Self {
+            name,
+            issuer,
+            issued,
+            expires: None,
+            credential_id: None,
+        }
+    }
+}
+
+type Row = (String, String, NaiveDate, Option<NaiveDate>, Option<String>);
+
+#[async_trait]
+impl DbModel for Certification {
+    const TABLE: &'static str = "Certification";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO Certification (id, name, issuer, issue_date, expiry_date, credential_id)
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.name)
+        .bind(&self.issuer)
+        .bind(self.issued)
+        .bind(self.expires)
+        .bind(&self.credential_id)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE Certification SET name=$1, issuer=$2, issue_date=$3, expiry_date=$4, credential_id=$5 WHERE id=$6;")
+        .bind(&self.name)
+        .bind(&self.issuer)
+        .bind(self.issued)
+        .bind(self.expires)
+        .bind(&self.credential_id)
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+        updated_one(result, "Certification", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (name, issuer, issued, expires, credential_id): Row = sqlx::query_as(
+            "SELECT name, issuer, issue_date, expiry_date, credential_id
+             FROM Certification WHERE id=$1;",
+        )
+        .bind(id)
+        .fetch_one(conn)
+        .await?;
+
+        Ok(Self {
+            name,
+            issuer,
+            issued,
+            expires,
+            credential_id,
+        })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let ids: Vec<i64> =
+            sqlx::query_scalar("SELECT id FROM Certification ORDER BY issue_date DESC, id;")
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut certifications = Vec::with_capacity(ids.len());
+        for id in ids {
+            certifications.push((id, Self::get_one_by_id(&mut *conn, id).await?));
+        }
+
+        Ok(certifications)
+    }
+}
@@ -0,0 +1,440 @@
+//! Validated ways of getting in touch, displayed in a resume's [`super::HeaderSection`].
+//!
+//! Every [`ContactMethod`] is checked when it's parsed from user input, so a malformed phone
+//! number, email address, or link is rejected long before it could end up on an exported resume.
+use std::fmt;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+use url::Url;
+
+use super::{updated_one, DbModel};
+use crate::state::Key;
+
+/// The most digits an E.164 phone number may have, including the country code.
+const MAX_PHONE_DIGITS: usize = 15;
+/// The fewest digits accepted for the subscriber number following the country code.
+const MIN_SUBSCRIBER_DIGITS: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContactMethod {
+    /// An E.164 phone number, split into the country calling code & the rest of the number
+    Phone {
+        country_code: u16,
+        number: String,
+    },
+    Email(String),
+    Link {
+        display: String,
+        href: Url,
+    },
+}
+
+impl ContactMethod {
+    /// Parse a phone number given as `+<country code> <number>`, e.g. `+1 555-555-5555`.
+    ///
+    /// The country code must be separated from the rest of the number by a space, dash, or dot,
+    /// any of which (along with parentheses) may also be used to group the rest of the number.
+    pub fn parse_phone(input: &str) -> anyhow::Result<Self> {
+        let Some(rest) = input.trim().strip_prefix('+') else {
+            anyhow::bail!(
+                "phone number `{input}` must start with `+` & a country code, e.g. `+1 555 555 5555`"
+            );
+        };
+        let Some((code, number)) = rest.split_once([' ', '-', '.']) else {
+            anyhow::bail!(
+                "phone number `{input}` must separate the country code from the rest of the \
+                 number, e.g. `+1 555 555 5555`"
+            );
+        };
+
+        if code.is_empty()
+            || code.len() > 3
+            || code.starts_with('0')
+            || !code.chars().all(|c| c.is_ascii_digit())
+        {
+            anyhow::bail!("phone number `{input}` has invalid country code `{code}`, expected 1-3 digits not starting with 0");
+        }
+
+        let mut digits = String::with_capacity(number.len());
+        for c in number.chars() {
+            match c {
+                '0'..='9' => digits.push(c),
+                ' ' | '-' | '.' | '(' | ')' => (),
+                _ => anyhow::bail!("phone number `{input}` contains invalid character `{c}`"),
+            }
+        }
+
+        if digits.len() < MIN_SUBSCRIBER_DIGITS {
+            anyhow::bail!("phone number `{input}` is too short");
+        }
+        if code.len() + digits.len() > MAX_PHONE_DIGITS {
+            anyhow::bail!(
+                "phone number `{input}` is too long, E.164 numbers have at most {MAX_PHONE_DIGITS} digits"
+            );
+        }
+
+        Ok(ContactMethod::Phone {
+            country_code: code.parse()?,
+            number: digits,
+        })
+    }
+
+    /// Parse an email address, checking it's syntax (but not that it's deliverable).
+    pub fn parse_email(input: &str) -> anyhow::Result<Self> {
+        let address = input.trim();
+        let Some((local, domain)) = address.rsplit_once('@') else {
+            anyhow::bail!("email address `{input}` is missing an `@`");
+        };
+
+        if local.is_empty() || local.len() > 64 {
+            anyhow::bail!("email address `{input}` must have 1-64 characters before the `@`");
+        }
+        if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+            anyhow::bail!("email address `{input}` can't have a leading, trailing, or doubled `.` before the `@`");
+        }
+        if let Some(c) = local
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(*c)))
+        {
+            anyhow::bail!("email address `{input}` contains invalid character `{c}`");
+        }
+
+        let labels: Vec<&str> = domain.split('.').collect();
+        if labels.len() < 2 {
+            anyhow::bail!(
+                "email address `{input}` must have a domain like `example.com` after the `@`"
+            );
+        }
+        for label in labels {
+            if label.is_empty()
+                || label.starts_with('-')
+                || label.ends_with('-')
+                || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            {
+                anyhow::bail!("email address `{input}` has an invalid domain `{domain}`");
+            }
+        }
+
+        Ok(ContactMethod::Email(address.to_string()))
+    }
+
+    /// Parse a link, requiring an absolute http(s) url.
+    pub fn parse_link(display: &str, href: &str) -> anyhow::Result<Self> {
+        Ok(ContactMethod::Link {
+            display: Self::parse_link_text(display)?,
+            href: Self::parse_href(href)?,
+        })
+    }
+
+    /// Parse the text a link displays, which can't be blank.
+    pub fn parse_link_text(input: &str) -> anyhow::Result<String> {
+        let display = input.trim();
+        if display.is_empty() {
+            anyhow::bail!("a link needs some text to display");
+        }
+
+        Ok(display.to_string())
+    }
+
+    /// Parse the url a link points to, which must be an absolute http(s) url.
+    pub fn parse_href(input: &str) -> anyhow::Result<Url> {
+        let href = Url::parse(input.trim())
+            .map_err(|e| anyhow::anyhow!("link `{input}` isn't a valid url: {e}"))?;
+        if !matches!(href.scheme(), "http" | "https") || href.host_str().is_none() {
+            anyhow::bail!("link `{href}` must be an http or https url");
+        }
+
+        Ok(href)
+    }
+
+    /// Where this contact method takes a reader when followed as a link.
+    pub fn href(&self) -> String {
+        match self {
+            ContactMethod::Phone {
+                country_code,
+                number,
+            } => format!("tel:+{country_code}{number}"),
+            ContactMethod::Email(address) => format!("mailto:{address}"),
+            ContactMethod::Link { href, .. } => href.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ContactMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContactMethod::Phone {
+                country_code,
+                number,
+            } => write!(f, "+{country_code} {number}"),
+            ContactMethod::Email(address) => write!(f, "{address}"),
+            ContactMethod::Link { display, .. } => write!(f, "{display}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_parse {
+    use super::*;
+
+    #[test]
+    fn phones_are_split_into_country_code_and_digits() {
+        let phone = ContactMethod::parse_phone("+1 (555) 555-0123").unwrap();
+
+        assert_eq!(
+            phone,
+            ContactMethod::Phone {
+                country_code: 1,
+                number: "5555550123".into()
+            }
+        );
+        assert_eq!(phone.href(), "tel:+15555550123");
+    }
+
+    #[test]
+    fn bad_phones_are_rejected() {
+        for bad in [
+            "555 555 0123",
+            "+15555550123",
+            "+0 555 0123",
+            "+1 555 CALL NOW",
+            "+1 55",
+            "+44 1234 5678 9012 3456",
+        ] {
+            assert!(
+                ContactMethod::parse_phone(bad).is_err(),
+                "`{bad}` should've been rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn emails_are_syntax_checked() {
+        assert_eq!(
+            ContactMethod::parse_email(" me+resumes@mail.example.com ").unwrap(),
+            ContactMethod::Email("me+resumes@mail.example.com".into())
+        );
+
+        for bad in [
+            "me",
+            "@example.com",
+            "me@localhost",
+            "me@-example.com",
+            "me..too@example.com",
+            "me@exa mple.com",
+        ] {
+            assert!(
+                ContactMethod::parse_email(bad).is_err(),
+                "`{bad}` should've been rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn links_must_be_web_urls() {
+        let link = ContactMethod::parse_link("GitHub", "https://github.com/me").unwrap();
+        assert_eq!(link.to_string(), "GitHub");
+        assert_eq!(link.href(), "https://github.com/me");
+
+        assert!(ContactMethod::parse_link("GitHub", "github.com/me").is_err());
+        assert!(ContactMethod::parse_link("Files", "file:///etc/passwd").is_err());
+        assert!(ContactMethod::parse_link("  ", "https://github.com/me").is_err());
+    }
+}
+
+/// A [`ContactMethod`] attached to a resume's header.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    pub header: Key,
+    pub method: ContactMethod,
+}
+
+impl Contact {
+    pub fn new(header: Key, method: ContactMethod) -> Self {
+        Self { header, method }
+    }
+
+    /// Get every contact method attached to the given header, in the order they were added.
+    pub async fn get_all_for(
+        conn: &mut SqliteConnection,
+        header: &Key,
+    ) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<Row> = sqlx::query_as(&format!(
+            "SELECT {COLUMNS} FROM ContactMethod WHERE header_id=$1 ORDER BY id;"
+        ))
+        .bind(header.db_id()?)
+        .fetch_all(conn)
+        .await?;
+
+        rows.into_iter().map(Self::from_row).collect()
+    }
+
+    fn from_row(row: Row) -> anyhow::Result<(i64, Self)> {
+        let (id, header, kind, country_code, number, address, display, href) = row;
+        let method = match (kind.as_str(), country_code, number, address, display, href) {
+            ("phone", Some(country_code), Some(number), _, _, _) => ContactMethod::Phone {
+                country_code: country_code.try_into()?,
+                number,
+            },
+            ("email", _, _, Some(address), _, _) => ContactMethod::Email(address),
+            ("link", _, _, _, Some(display), Some(href)) => ContactMethod::Link {
+                display,
+                href: Url::parse(&href)?,
+            },
+            (kind, ..) => anyhow::bail!("ContactMethod {id} has incomplete data for kind `{kind}`"),
+        };
+
+        Ok((id, Self::new(Key::Db(header), method)))
+    }
+}
+
+impl ContactMethod {
+    /// Values for the kind specific columns of the ContactMethod table, in table order.
+    fn columns(&self) -> MethodColumns {
+        match self {
+            ContactMethod::Phone {
+                country_code,
+                number,
+            } => (
+                "phone",
+                Some(i64::from(*country_code)),
+                Some(number.clone()),
+                None,
+                None,
+                None,
+            ),
+            ContactMethod::Email(address) => {
+                ("email", None, None, Some(address.clone()), None, None)
+            }
+            ContactMethod::Link { display, href } => (
+                "link",
+                None,
+                None,
+                None,
+                Some(display.clone()),
+                Some(href.to_string()),
+            ),
+        }
+    }
+}
+
+type MethodColumns = (
+    &'static str,
+    Option<i64>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+/// A row from the ContactMethod table, only some of the nullable columns are used by any one kind
+/// of contact method.
+type Row = (
+    i64,
+    i64,
+    String,
+    Option<i64>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+const COLUMNS: &str = "id, header_id, kind, country_code, number, email_address, display, href";
+
+#[async_trait]
+impl DbModel for Contact {
+    const TABLE: &'static str = "ContactMethod";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let (kind, country_code, number, email_address, display, href) = self.method.columns();
+        sqlx::query_scalar(
+            "INSERT INTO ContactMethod
+             (id, header_id, kind, country_code, number, email_address, display, href)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id;",
+        )
+        .bind(id)
+        .bind(self.header.db_id()?)
+        .bind(kind)
+        .bind(country_code)
+        .bind(number)
+        .bind(email_address)
+        .bind(display)
+        .bind(href)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let (kind, country_code, number, email_address, display, href) = self.method.columns();
+        let result = sqlx::query(
+            "UPDATE ContactMethod SET header_id=$1, kind=$2, country_code=$3, number=$4,
+             email_address=$5, display=$6, href=$7 WHERE id=$8;",
+        )
+        .bind(self.header.db_id()?)
+        .bind(kind)
+        .bind(country_code)
+        .bind(number)
+        .bind(email_address)
+        .bind(display)
+        .bind(href)
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+        updated_one(result, "ContactMethod", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let row: Row = sqlx::query_as(&format!("SELECT {COLUMNS} FROM ContactMethod WHERE id=$1;"))
+            .bind(id)
+            .fetch_one(conn)
+            .await?;
+
+        Self::from_row(row).map(|(_, contact)| contact)
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<Row> =
+            sqlx::query_as(&format!("SELECT {COLUMNS} FROM ContactMethod ORDER BY id;"))
+                .fetch_all(conn)
+                .await?;
+
+        rows.into_iter().map(Self::from_row).collect()
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn every_kind_of_contact_round_trips_through_db() {
+    use super::{HeaderSection, Resume};
+
+    let pool = super::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+    let resume = Key::Db(Resume::new("Mine".into()).create(&mut conn).await.unwrap());
+    let header = Key::Db(
+        HeaderSection::new(resume, "Me".into())
+            .create(&mut conn)
+            .await
+            .unwrap(),
+    );
+
+    let mut expected = Vec::new();
+    for method in [
+        ContactMethod::parse_phone("+44 20 7946 0958").unwrap(),
+        ContactMethod::parse_email("me@example.com").unwrap(),
+        ContactMethod::parse_link("Site", "https://example.com").unwrap(),
+    ] {
+        let contact = Contact::new(header, method);
+        let id = contact.create(&mut conn).await.unwrap();
+        expected.push((id, contact));
+    }
+
+    assert_eq!(
+        Contact::get_all_for(&mut conn, &header).await.unwrap(),
+        expected
+    );
+}
@@ -0,0 +1,271 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel};
+use crate::state::Key;
+
+/// What a [`DescriptionItem`] is describing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Parent {
+    Job(Key),
+    Project(Key),
+}
+
+/// A single bullet point describing a Job or Project.
+///
+/// Items are displayed in ascending order of `position` amongst items sharing the same parent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DescriptionItem {
+    pub parent: Parent,
+    pub position: i64,
+    pub text: String,
+    /// Alternative phrasings of the same point, each with a label unique to this item
+    pub variants: Vec<Variant>,
+}
+
+/// An alternative phrasing of a [`DescriptionItem`], e.g. a shorter or less technical one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+    /// e.g. short, long, technical, managerial, etc.
+    pub label: String,
+    pub text: String,
+}
+
+impl Variant {
+    pub fn new(label: String, text: String) -> Self {
+        Self { label, text }
+    }
+}
+
+impl DescriptionItem {
+    pub fn new(parent: Parent, position: i64, text: String) -> Self {
+        Self {
+            parent,
+            position,
+            text,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Get the variant with the given label, ignoring case, if there is one.
+    pub fn variant(&self, label: &str) -> Option<&Variant> {
+        let label = label.to_lowercase();
+
+        self.variants
+            .iter()
+            .find(|variant| variant.label.to_lowercase() == label)
+    }
+
+    /// Get every item describing the given parent, in display order.
+    pub async fn get_all_for(
+        conn: &mut SqliteConnection,
+        parent: &Parent,
+    ) -> anyhow::Result<Vec<(i64, Self)>> {
+        let (column, key) = match parent {
+            Parent::Job(key) => ("job_id", key),
+            Parent::Project(key) => ("project_id", key),
+        };
+        let rows: Vec<(i64, i64, String)> = sqlx::query_as(&format!(
+            "SELECT id, position, text FROM DescriptionItem WHERE {column}=$1 ORDER BY position, id;"
+        ))
+        .bind(key.db_id()?)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut items = Vec::with_capacity(rows.len());
+        for (id, position, text) in rows {
+            let mut item = Self::new(*parent, position, text);
+            item.variants = Self::variants(&mut *conn, id).await?;
+            items.push((id, item));
+        }
+
+        Ok(items)
+    }
+
+    async fn insert_variants(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        for variant in &self.variants {
+            sqlx::query(
+                "INSERT INTO DescriptionVariant (item_id, label, text) VALUES ($1, $2, $3);",
+            )
+            .bind(id)
+            .bind(&variant.label)
+            .bind(&variant.text)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn variants(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Vec<Variant>> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT label, text FROM DescriptionVariant WHERE item_id=$1 ORDER BY id;",
+        )
+        .bind(id)
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(label, text)| Variant::new(label, text))
+            .collect())
+    }
+
+    /// The job & project ids to store, only one of which is ever given.
+    fn parent_ids(&self) -> anyhow::Result<(Option<i64>, Option<i64>)> {
+        Ok(match self.parent {
+            Parent::Job(key) => (Some(key.db_id()?), None),
+            Parent::Project(key) => (None, Some(key.db_id()?)),
+        })
+    }
+
+    fn from_row((job, project, position, text): Row) -> anyhow::Result<Self> {
+        let parent = match (job, project) {
+            (Some(job), None) => Parent::Job(Key::Db(job)),
+            (None, Some(project)) => Parent::Project(Key::Db(project)),
+            _ => anyhow::bail!("DescriptionItem must belong to exactly one Job or Project"),
+        };
+
+        Ok(Self::new(parent, position, text))
+    }
+}
+
+type Row = (Option<i64>, Option<i64>, i64, String);
+
+#[async_trait]
+impl DbModel for DescriptionItem {
+    const TABLE: &'static str = "DescriptionItem";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let (job, project) = self.parent_ids()?;
+        let id = sqlx::query_scalar(
+            "INSERT INTO DescriptionItem (id, job_id, project_id, position, text)
+             VALUES ($1, $2, $3, $4, $5) RETURNING id;",
+        )
+        .bind(id)
+        .bind(job)
+        .bind(project)
+        .bind(self.position)
+        .bind(&self.text)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        self.insert_variants(conn, id).await?;
+
+        Ok(id)
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let (job, project) = self.parent_ids()?;
+        let result = sqlx::query(
+            "UPDATE DescriptionItem SET job_id=$1, project_id=$2, position=$3, text=$4
+             WHERE id=$5;",
+        )
+        .bind(job)
+        .bind(project)
+        .bind(self.position)
+        .bind(&self.text)
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+        updated_one(result, "DescriptionItem", id)?;
+        sqlx::query("DELETE FROM DescriptionVariant WHERE item_id=$1;")
+            .bind(id)
+            .execute(&mut *conn)
+            .await?;
+
+        self.insert_variants(conn, id).await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let row: Row = sqlx::query_as(
+            "SELECT job_id, project_id, position, text FROM DescriptionItem WHERE id=$1;",
+        )
+        .bind(id)
+        .fetch_one(&mut *conn)
+        .await?;
+        let mut item = Self::from_row(row)?;
+        item.variants = Self::variants(conn, id).await?;
+
+        Ok(item)
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, Option<i64>, Option<i64>, i64, String)> = sqlx::query_as(
+            "SELECT id, job_id, project_id, position, text FROM DescriptionItem
+             ORDER BY position, id;",
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut items = Vec::with_capacity(rows.len());
+        for (id, job, project, position, text) in rows {
+            let mut item = Self::from_row((job, project, position, text))?;
+            item.variants = Self::variants(&mut *conn, id).await?;
+            items.push((id, item));
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{test_pool, Job};
+
+    #[tokio::test]
+    async fn variants_round_trip_with_their_bullet() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let job = Job::new(
+            "Acme".into(),
+            "Engineer".into(),
+            "2020-01-01".parse().unwrap(),
+            None,
+            Vec::new(),
+        )
+        .create(&mut conn)
+        .await
+        .unwrap();
+        let mut item = DescriptionItem::new(Parent::Job(Key::Db(job)), 0, "Cut costs".into());
+        item.variants = vec![
+            Variant::new("short".into(), "Saved $1M".into()),
+            Variant::new(
+                "technical".into(),
+                "Moved batch jobs to spot instances".into(),
+            ),
+        ];
+
+        let id = item.create(&mut conn).await.unwrap();
+        let loaded = DescriptionItem::get_one_by_id(&mut conn, id).await.unwrap();
+
+        assert_eq!(loaded, item);
+        assert_eq!(loaded.variant("Short").unwrap().text, "Saved $1M");
+        assert!(loaded.variant("managerial").is_none());
+    }
+
+    #[tokio::test]
+    async fn variant_labels_are_unique_to_a_bullet() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let job = Job::new(
+            "Acme".into(),
+            "Engineer".into(),
+            "2020-01-01".parse().unwrap(),
+            None,
+            Vec::new(),
+        )
+        .create(&mut conn)
+        .await
+        .unwrap();
+        let mut item = DescriptionItem::new(Parent::Job(Key::Db(job)), 0, "Cut costs".into());
+        item.variants = vec![
+            Variant::new("short".into(), "Saved $1M".into()),
+            Variant::new("SHORT".into(), "Saved money".into()),
+        ];
+
+        assert!(item.create(&mut conn).await.is_err());
+    }
+}
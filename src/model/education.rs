@@ -0,0 +1,276 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel, Developer};
+use crate::state::Key;
+
+/// The highest GPA accepted, high enough for the common 4, 5 & 10 point scales.
+const MAX_GPA: f64 = 10.0;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Degree {
+    /// e.g. Associate's, Bachelor's, Master's, etc.
+    pub level: String,
+    pub major: String,
+    pub minor: Option<String>,
+    pub gpa: Option<f64>,
+    pub description: Option<String>,
+    /// Skills developed over the course of the whole degree
+    pub skills: Vec<Key>,
+}
+
+impl Degree {
+    pub fn new(level: String, major: String) -> Self {
+        Self {
+            level,
+            major,
+            minor: None,
+            gpa: None,
+            description: None,
+            skills: Vec::new(),
+        }
+    }
+
+    /// Parse a GPA from user input, rejecting anything that isn't a number from 0 up to
+    /// [`MAX_GPA`].
+    pub fn parse_gpa(input: &str) -> anyhow::Result<f64> {
+        let gpa: f64 = input
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("GPA `{input}` must be a number, e.g. `3.5`"))?;
+        if !(0.0..=MAX_GPA).contains(&gpa) {
+            anyhow::bail!("GPA `{input}` must be between 0 and {MAX_GPA}");
+        }
+
+        Ok(gpa)
+    }
+}
+
+type DegreeRow = (String, String, Option<String>, Option<f64>, Option<String>);
+
+#[async_trait]
+impl DbModel for Degree {
+    const TABLE: &'static str = "Degree";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let id = sqlx::query_scalar(
+            "INSERT INTO Degree (id, level, major, minor, gpa, description)
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.level)
+        .bind(&self.major)
+        .bind(&self.minor)
+        .bind(self.gpa)
+        .bind(&self.description)
+        .fetch_one(&mut *conn)
+        .await?;
+        Developer::Degree(id)
+            .link_skills(conn, &self.skills)
+            .await?;
+
+        Ok(id)
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query(
+            "UPDATE Degree SET level=$1, major=$2, minor=$3, gpa=$4, description=$5 WHERE id=$6;",
+        )
+        .bind(&self.level)
+        .bind(&self.major)
+        .bind(&self.minor)
+        .bind(self.gpa)
+        .bind(&self.description)
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+        updated_one(result, "Degree", id)?;
+
+        Developer::Degree(id)
+            .relink_skills(conn, &self.skills)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (level, major, minor, gpa, description): DegreeRow =
+            sqlx::query_as("SELECT level, major, minor, gpa, description FROM Degree WHERE id=$1;")
+                .bind(id)
+                .fetch_one(&mut *conn)
+                .await?;
+        let skills = Developer::Degree(id).skills(conn).await?;
+
+        Ok(Self {
+            level,
+            major,
+            minor,
+            gpa,
+            description,
+            skills,
+        })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM Degree;")
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let mut degrees = Vec::with_capacity(ids.len());
+        for id in ids {
+            degrees.push((id, Self::get_one_by_id(&mut *conn, id).await?));
+        }
+
+        Ok(degrees)
+    }
+}
+
+/// A single course studied as part of a [`Degree`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Course {
+    pub degree: Key,
+    pub name: String,
+    /// Skills developed while taking this course
+    pub skills: Vec<Key>,
+}
+
+impl Course {
+    pub fn new(degree: Key, name: String, skills: Vec<Key>) -> Self {
+        Self {
+            degree,
+            name,
+            skills,
+        }
+    }
+
+    /// Get every course studied for the given degree.
+    pub async fn get_all_for(
+        conn: &mut SqliteConnection,
+        degree: &Key,
+    ) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, String)> =
+            sqlx::query_as("SELECT id, name FROM Course WHERE degree_id=$1;")
+                .bind(degree.db_id()?)
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut courses = Vec::with_capacity(rows.len());
+        for (id, name) in rows {
+            let skills = Developer::Course(id).skills(&mut *conn).await?;
+            courses.push((id, Self::new(*degree, name, skills)));
+        }
+
+        Ok(courses)
+    }
+}
+
+#[async_trait]
+impl DbModel for Course {
+    const TABLE: &'static str = "Course";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let id = sqlx::query_scalar(
+            "INSERT INTO Course (id, degree_id, name) VALUES ($1, $2, $3) RETURNING id;",
+        )
+        .bind(id)
+        .bind(self.degree.db_id()?)
+        .bind(&self.name)
+        .fetch_one(&mut *conn)
+        .await?;
+        Developer::Course(id)
+            .link_skills(conn, &self.skills)
+            .await?;
+
+        Ok(id)
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE Course SET degree_id=$1, name=$2 WHERE id=$3;")
+            .bind(self.degree.db_id()?)
+            .bind(&self.name)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?;
+        updated_one(result, "Course", id)?;
+
+        Developer::Course(id)
+            .relink_skills(conn, &self.skills)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (degree, name) = sqlx::query_as("SELECT degree_id, name FROM Course WHERE id=$1;")
+            .bind(id)
+            .fetch_one(&mut *conn)
+            .await?;
+        let skills = Developer::Course(id).skills(conn).await?;
+
+        Ok(Self::new(Key::Db(degree), name, skills))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, i64, String)> =
+            sqlx::query_as("SELECT id, degree_id, name FROM Course;")
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut courses = Vec::with_capacity(rows.len());
+        for (id, degree, name) in rows {
+            let skills = Developer::Course(id).skills(&mut *conn).await?;
+            courses.push((id, Self::new(Key::Db(degree), name, skills)));
+        }
+
+        Ok(courses)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{test_pool, Skill};
+
+    #[test]
+    fn gpas_must_be_in_range() {
+        assert_eq!(Degree::parse_gpa(" 3.5 ").unwrap(), 3.5);
+        assert_eq!(Degree::parse_gpa("0").unwrap(), 0.0);
+        assert_eq!(Degree::parse_gpa("10").unwrap(), 10.0);
+        for bad in ["-1", "99.0", "NaN", "inf", "four", ""] {
+            assert!(
+                Degree::parse_gpa(bad).is_err(),
+                "`{bad}` should be rejected"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn courses_are_found_by_degree_with_developed_skills() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let sql = Key::Db(
+            Skill::new("SQL".into(), None)
+                .create(&mut conn)
+                .await
+                .unwrap(),
+        );
+        let mut degree = Degree::new("B.S.".into(), "Computer Science".into());
+        degree.gpa = Some(3.5);
+        let degree = Key::Db(degree.create(&mut conn).await.unwrap());
+        let other = Key::Db(
+            Degree::new("B.A.".into(), "History".into())
+                .create(&mut conn)
+                .await
+                .unwrap(),
+        );
+
+        let databases = Course::new(degree, "Databases".into(), vec![sql]);
+        let id = databases.create(&mut conn).await.unwrap();
+        Course::new(other, "Rome".into(), vec![])
+            .create(&mut conn)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Course::get_all_for(&mut conn, &degree).await.unwrap(),
+            vec![(id, databases)]
+        );
+    }
+}
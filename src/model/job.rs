@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel, Developer};
+use crate::state::Key;
+
+/// A position held at some employer, from `start` until `end` (or the present, if no end).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    pub employer: String,
+    pub title: String,
+    pub start: NaiveDate,
+    pub end: Option<NaiveDate>,
+    /// Skills practiced while working this job
+    pub skills: Vec<Key>,
+}
+
+impl Job {
+    pub fn new(
+        employer: String,
+        title: String,
+        start: NaiveDate,
+        end: Option<NaiveDate>,
+        skills: Vec<Key>,
+    ) -> Self {
+        Self {
+            employer,
+            title,
+            start,
+            end,
+            skills,
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for Job {
+    const TABLE: &'static str = "Job";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let id = sqlx::query_scalar(
+            "INSERT INTO Job (id, employer, job_title, start_date, end_date)
+             VALUES ($1, $2, $3, $4, $5) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.employer)
+        .bind(&self.title)
+        .bind(self.start)
+        .bind(self.end)
+        .fetch_one(&mut *conn)
+        .await?;
+        Developer::Job(id).link_skills(conn, &self.skills).await?;
+
+        Ok(id)
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query(
+            "UPDATE Job SET employer=$1, job_title=$2, start_date=$3, end_date=$4 WHERE id=$5;",
+        )
+        .bind(&self.employer)
+        .bind(&self.title)
+        .bind(self.start)
+        .bind(self.end)
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+        updated_one(result, "Job", id)?;
+
+        Developer::Job(id).relink_skills(conn, &self.skills).await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (employer, title, start, end) = sqlx::query_as(
+            "SELECT employer, job_title, start_date, end_date FROM Job WHERE id=$1;",
+        )
+        .bind(id)
+        .fetch_one(&mut *conn)
+        .await?;
+        let skills = Developer::Job(id).skills(conn).await?;
+
+        Ok(Self::new(employer, title, start, end, skills))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, String, String, NaiveDate, Option<NaiveDate>)> = sqlx::query_as(
+            "SELECT id, employer, job_title, start_date, end_date FROM Job
+             ORDER BY start_date DESC;",
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut jobs = Vec::with_capacity(rows.len());
+        for (id, employer, title, start, end) in rows {
+            let skills = Developer::Job(id).skills(&mut *conn).await?;
+            jobs.push((id, Self::new(employer, title, start, end, skills)));
+        }
+
+        Ok(jobs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{test_pool, DescriptionItem, Parent, Skill};
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn jobs_round_trip_with_practiced_skills() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let rust = Skill::new("Rust".into(), None)
+            .create(&mut conn)
+            .await
+            .unwrap();
+        let sql = Skill::new("SQL".into(), None)
+            .create(&mut conn)
+            .await
+            .unwrap();
+
+        let job = Job::new(
+            "Acme".into(),
+            "Engineer".into(),
+            date("2020-01-01"),
+            None,
+            vec![Key::Db(rust), Key::Db(sql)],
+        );
+        let id = job.create(&mut conn).await.unwrap();
+
+        assert_eq!(Job::get_one_by_id(&mut conn, id).await.unwrap(), job);
+    }
+
+    #[tokio::test]
+    async fn deleting_a_job_removes_its_bullets_and_skill_links() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let rust = Skill::new("Rust".into(), None)
+            .create(&mut conn)
+            .await
+            .unwrap();
+        let job = Job::new(
+            "Acme".into(),
+            "Engineer".into(),
+            date("2020-01-01"),
+            Some(date("2022-06-01")),
+            vec![Key::Db(rust)],
+        )
+        .create(&mut conn)
+        .await
+        .unwrap();
+        DescriptionItem::new(Parent::Job(Key::Db(job)), 0, "Did things".into())
+            .create(&mut conn)
+            .await
+            .unwrap();
+
+        sqlx::query("DELETE FROM Job WHERE id=$1;")
+            .bind(job)
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+
+        let bullets = DescriptionItem::get_all(&mut conn).await.unwrap();
+        let links: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM DevelopedSkill;")
+            .fetch_one(&mut *conn)
+            .await
+            .unwrap();
+        assert!(bullets.is_empty(), "{bullets:?} should've been deleted");
+        assert_eq!(links, 0);
+        // the skill itself is master data & outlives the job
+        assert!(Skill::get_one_by_id(&mut conn, rust).await.is_ok());
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel};
+
+/// How well a language is spoken, loosely following the ILR scale.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Proficiency {
+    Elementary,
+    Limited,
+    Professional,
+    FullProfessional,
+    Native,
+}
+
+impl Proficiency {
+    /// The value stored in the `Language` table's `proficiency` column.
+    fn as_str(&self) -> &'static str {
+        match self {
+            Proficiency::Elementary => "elementary",
+            Proficiency::Limited => "limited",
+            Proficiency::Professional => "professional",
+            Proficiency::FullProfessional => "full-professional",
+            Proficiency::Native => "native",
+        }
+    }
+
+    fn from_column(value: &str) -> anyhow::Result<Self> {
+        Self::from_str(value, false)
+            .map_err(|_| anyhow::anyhow!("Unknown language proficiency: {value}"))
+    }
+}
+
+impl fmt::Display for Proficiency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Proficiency::Elementary => "Elementary",
+            Proficiency::Limited => "Limited working",
+            Proficiency::Professional => "Professional working",
+            Proficiency::FullProfessional => "Full professional",
+            Proficiency::Native => "Native",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+/// A spoken language.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Language {
+    pub name: String,
+    pub proficiency: Proficiency,
+}
+
+impl Language {
+    pub fn new(name: String, proficiency: Proficiency) -> Self {
+        Self { name, proficiency }
+    }
+}
+
+#[async_trait]
+impl DbModel for Language {
+    const TABLE: &'static str = "Language";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO Language (id, name, proficiency) VALUES ($1, $2, $3) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.name)
+        .bind(self.proficiency.as_str())
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE Language SET name=$1, proficiency=$2 WHERE id=$3;")
+            .bind(&self.name)
+            .bind(self.proficiency.as_str())
+            .bind(id)
+            .execute(conn)
+            .await?;
+
+        updated_one(result, "Language", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (name, proficiency): (String, String) =
+            sqlx::query_as("SELECT name, proficiency FROM Language WHERE id=$1;")
+                .bind(id)
+                .fetch_one(conn)
+                .await?;
+
+        Ok(Self::new(name, Proficiency::from_column(&proficiency)?))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, String, String)> =
+            sqlx::query_as("SELECT id, name, proficiency FROM Language ORDER BY name;")
+                .fetch_all(conn)
+                .await?;
+
+        rows.into_iter()
+            .map(|(id, name, proficiency)| {
+                Ok((id, Self::new(name, Proficiency::from_column(&proficiency)?)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn every_proficiency_round_trips_through_db() {
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+
+    for (i, proficiency) in Proficiency::value_variants().iter().enumerate() {
+        let language = Language::new(format!("Language {i}"), *proficiency);
+        let id = language.create(&mut conn).await.unwrap();
+
+        assert_eq!(
+            Language::get_one_by_id(&mut conn, id).await.unwrap(),
+            language
+        );
+    }
+}
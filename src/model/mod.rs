@@ -1,34 +1,96 @@
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteQueryResult, SqliteConnection, SqlitePool};
 
-// pub mod skill;
-// pub use skill::Skill;
+pub mod award;
+pub mod certification;
+pub mod contact;
+pub mod description;
+pub mod education;
+pub mod job;
+pub mod language;
+pub mod project;
+pub mod publication;
+pub mod section;
+pub mod selection;
+pub mod skill;
+pub mod volunteer;
 
+pub use award::Award;
+pub use certification::Certification;
+pub use contact::{Contact, ContactMethod};
+pub use description::{DescriptionItem, Parent, Variant};
+pub use education::{Course, Degree};
+pub use job::Job;
+pub use language::{Language, Proficiency};
+pub use project::Project;
+pub use publication::Publication;
+pub use section::{
+    AwardSection, CertificationSection, EducationSection, ExperienceSection, HeaderSection,
+    LanguageSection, ListedSection, ProjectSection, PublicationSection, SkillsSection,
+    SummarySection, VolunteerSection,
+};
+pub use selection::SelectedBullet;
+pub use skill::{Developer, Skill, SkillCategory};
+pub use volunteer::Volunteer;
+
+/// Database behaviours shared by every model type.
+///
+/// Methods take a connection instead of a pool so they can be run inside a transaction as easily
+/// as against a freshly acquired connection. Any [`Key`](crate::state::Key) held by a model
+/// must already be a `Key::Db` before it can be written.
 #[async_trait]
-pub trait DbModel {
-    // pub trait Model<T: Clone>: Id<T> {
-    type Output;
-    async fn create(pool: &SqlitePool, name: &String) -> anyhow::Result<Self::Output>;
-    // async fn get_one_by_id(pool: &SqlitePool, id: &T) -> anyhow::Result<Self::Output>;
-    // async fn get_one_includes(pool: &SqlitePool, query: &String) -> anyhow::Result<Self::Output>;
-    // async fn get_all(pool: &SqlitePool) -> anyhow::Result<Vec<Self::Output>>;
-}
-
-// pub trait Id<T: Clone> {
-//     fn id_field(&self) -> &T;
-//
-//     fn borrow_id(&self) -> &T {
-//         self.id_field()
-//     }
-//     fn clone_id(&self) -> T {
-//         let id = self.id_field();
-//         id.clone()
-//     }
-// }
-
-#[derive(Debug, Clone)]
+pub trait DbModel: Sized {
+    /// The table holding one row per value.
+    const TABLE: &'static str;
+
+    /// Insert a new row (& any rows it owns) built from this value, returning the new row's id.
+    async fn create(&self, conn: &mut SqliteConnection) -> anyhow::Result<i64> {
+        self.insert(conn, None).await
+    }
+    /// Insert a new row like [`DbModel::create`], but under the given id if there is one, e.g. to
+    /// put back a row that was deleted.
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64>;
+    /// Overwrite the row stored under the given id (& any rows it owns) with this value.
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()>;
+    /// Get the value stored under the given id.
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self>;
+    /// Get every value of this type, paired with it's id.
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>>;
+    /// Delete the row stored under the given id, along with any rows it owns.
+    async fn delete(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        sqlx::query(&format!("DELETE FROM {} WHERE id=$1;", Self::TABLE))
+            .bind(id)
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Get the `M` saved under the given id, or `None` if there isn't one.
+pub async fn get_if_saved<M>(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Option<M>>
+where
+    M: DbModel,
+{
+    match M::get_one_by_id(conn, id).await {
+        Ok(saved) => Ok(Some(saved)),
+        Err(e) if matches!(e.downcast_ref(), Some(sqlx::Error::RowNotFound)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Fail unless an `UPDATE` changed the one row it was meant to.
+fn updated_one(result: SqliteQueryResult, table: &str, id: i64) -> anyhow::Result<()> {
+    match result.rows_affected() {
+        0 => anyhow::bail!("No {table} with id {id} to update"),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Resume {
-    name: String,
+    pub name: String,
 }
 
 impl Resume {
@@ -37,14 +99,120 @@ impl Resume {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Contact {
-    display: String,
-    href: String,
+#[async_trait]
+impl DbModel for Resume {
+    const TABLE: &'static str = "Resume";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar("INSERT INTO Resume (id, name) VALUES ($1, $2) RETURNING id;")
+            .bind(id)
+            .bind(&self.name)
+            .fetch_one(conn)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE Resume SET name=$1 WHERE id=$2;")
+            .bind(&self.name)
+            .bind(id)
+            .execute(conn)
+            .await?;
+
+        updated_one(result, "Resume", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (name,) = sqlx::query_as("SELECT name FROM Resume WHERE id=$1;")
+            .bind(id)
+            .fetch_one(conn)
+            .await?;
+
+        Ok(Self { name })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, String)> = sqlx::query_as("SELECT id, name FROM Resume;")
+            .fetch_all(conn)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name)| (id, Self { name }))
+            .collect())
+    }
 }
 
-impl Contact {
-    pub fn new(display: String, href: String) -> Self {
-        Self { display, href }
+/// Bring the db's schema up to date.
+///
+/// Foreign keys are off while migrating, as some migrations rebuild tables, which would otherwise
+/// cascade to every row referencing them, so are checked once they're done instead.
+pub async fn migrate(pool: &SqlitePool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    sqlx::query("PRAGMA foreign_keys = OFF;")
+        .execute(&mut *conn)
+        .await?;
+    let migrated = sqlx::migrate!().run(&mut *conn).await;
+    sqlx::query("PRAGMA foreign_keys = ON;")
+        .execute(&mut *conn)
+        .await?;
+    migrated?;
+
+    let broken: Vec<(String, i64, String, i64)> = sqlx::query_as("PRAGMA foreign_key_check;")
+        .fetch_all(&mut *conn)
+        .await?;
+    if let Some((table, id, parent, _)) = broken.first() {
+        anyhow::bail!("Migrating left {table} {id} referencing a missing {parent}");
     }
+
+    Ok(())
+}
+
+/// An empty, fully migrated, in-memory database for testing models against.
+#[cfg(test)]
+pub(crate) async fn test_pool() -> sqlx::SqlitePool {
+    // every connection to `sqlite::memory:` gets it's own database, so only ever open one
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    migrate(&pool).await.unwrap();
+
+    pool
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn resumes_round_trip_through_db() {
+    let pool = test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+
+    let resume = Resume::new("Backend roles".into());
+    let id = resume.create(&mut conn).await.unwrap();
+
+    assert_eq!(Resume::get_one_by_id(&mut conn, id).await.unwrap(), resume);
+    assert_eq!(
+        Resume::get_all(&mut conn).await.unwrap(),
+        vec![(id, resume)]
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn deleted_ids_are_never_reused() {
+    let pool = test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+
+    let deleted = Resume::new("Jane Doe".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    Resume::delete(&mut conn, deleted).await.unwrap();
+    let added = Resume::new("Frontend".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+
+    assert_ne!(added, deleted);
 }
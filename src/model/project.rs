@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+use url::Url;
+
+use super::{updated_one, DbModel, Developer};
+use crate::state::Key;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    pub name: String,
+    /// Where the project's source can be found
+    pub repository: Option<Url>,
+    /// Where the project can be seen running
+    pub demo: Option<Url>,
+    pub start: Option<NaiveDate>,
+    /// When work on the project ended, if it has
+    pub end: Option<NaiveDate>,
+    /// Skills practiced while working on this project
+    pub skills: Vec<Key>,
+}
+
+impl Project {
+    pub fn new(name: String, skills: Vec<Key>) -> Self {
+        Self {
+            name,
+            repository: None,
+            demo: None,
+            start: None,
+            end: None,
+            skills,
+        }
+    }
+}
+
+type Row = (
+    String,
+    Option<String>,
+    Option<String>,
+    Option<NaiveDate>,
+    Option<NaiveDate>,
+);
+
+#[async_trait]
+impl DbModel for Project {
+    const TABLE: &'static str = "Project";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let id = sqlx::query_scalar(
+            "INSERT INTO Project (id, name, repository, demo, start_date, end_date)
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.name)
+        .bind(self.repository.as_ref().map(Url::as_str))
+        .bind(self.demo.as_ref().map(Url::as_str))
+        .bind(self.start)
+        .bind(self.end)
+        .fetch_one(&mut *conn)
+        .await?;
+        Developer::Project(id)
+            .link_skills(conn, &self.skills)
+            .await?;
+
+        Ok(id)
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE Project SET name=$1, repository=$2, demo=$3, start_date=$4, end_date=$5 WHERE id=$6;")
+        .bind(&self.name)
+        .bind(self.repository.as_ref().map(Url::as_str))
+        .bind(self.demo.as_ref().map(Url::as_str))
+        .bind(self.start)
+        .bind(self.end)
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+        updated_one(result, "Project", id)?;
+
+        Developer::Project(id)
+            .relink_skills(conn, &self.skills)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (name, repository, demo, start, end): Row = sqlx::query_as(
+            "SELECT name, repository, demo, start_date, end_date FROM Project WHERE id=$1;",
+        )
+        .bind(id)
+        .fetch_one(&mut *conn)
+        .await?;
+        let skills = Developer::Project(id).skills(conn).await?;
+
+        Ok(Self {
+            name,
+            repository: repository.as_deref().map(Url::parse).transpose()?,
+            demo: demo.as_deref().map(Url::parse).transpose()?,
+            start,
+            end,
+            skills,
+        })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let ids: Vec<i64> =
+            sqlx::query_scalar("SELECT id FROM Project ORDER BY start_date DESC, id;")
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut projects = Vec::with_capacity(ids.len());
+        for id in ids {
+            projects.push((id, Self::get_one_by_id(&mut *conn, id).await?));
+        }
+
+        Ok(projects)
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn projects_round_trip_with_links_and_dates() {
+    let pool = crate::model::test_pool().await;
+    let mut conn = pool.acquire().await.unwrap();
+
+    let mut project = Project::new("res-gen".into(), Vec::new());
+    project.repository = Some("https://github.com/me/res-gen".parse().unwrap());
+    project.start = Some("2024-09-01".parse().unwrap());
+    let id = project.create(&mut conn).await.unwrap();
+
+    assert_eq!(
+        Project::get_one_by_id(&mut conn, id).await.unwrap(),
+        project
+    );
+}
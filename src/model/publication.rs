@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+use url::Url;
+
+use super::{updated_one, DbModel};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Publication {
+    pub title: String,
+    /// Journal, conference, blog, etc. the work was published in
+    pub publisher: Option<String>,
+    pub published: Option<NaiveDate>,
+    /// Where the work can be read
+    pub url: Option<Url>,
+}
+
+impl Publication {
+    pub fn new(title: String) -> Self {
+        Self {
+            title,
+            publisher: None,
+            published: None,
+            url: None,
+        }
+    }
+}
+
+type Row = (String, Option<String>, Option<NaiveDate>, Option<String>);
+
+#[async_trait]
+impl DbModel for Publication {
+    const TABLE: &'static str = "Publication";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO Publication (id, title, publisher, publish_date, url)
+             VALUES ($1, $2, $3, $4, $5) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.title)
+        .bind(&self.publisher)
+        .bind(self.published)
+        .bind(self.url.as_ref().map(Url::as_str))
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query(
+            "UPDATE Publication SET title=$1, publisher=$2, publish_date=$3, url=$4 WHERE id=$5;",
+        )
+        .bind(&self.title)
+        .bind(&self.publisher)
+        .bind(self.published)
+        .bind(self.url.as_ref().map(Url::as_str))
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+        updated_one(result, "Publication", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (title, publisher, published, url): Row = sqlx::query_as(
+            "SELECT title, publisher, publish_date, url FROM Publication WHERE id=$1;",
+        )
+        .bind(id)
+        .fetch_one(conn)
+        .await?;
+
+        Ok(Self {
+            title,
+            publisher,
+            published,
+            url: url.as_deref().map(Url::parse).transpose()?,
+        })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let ids: Vec<i64> =
+            sqlx::query_scalar("SELECT id FROM Publication ORDER BY publish_date DESC, id;")
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut publications = Vec::with_capacity(ids.len());
+        for id in ids {
+            publications.push((id, Self::get_one_by_id(&mut *conn, id).await?));
+        }
+
+        Ok(publications)
+    }
+}
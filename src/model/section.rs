@@ -0,0 +1,999 @@
+//! The sections making up a [`super::Resume`].
+//!
+//! A resume has at most one of each section. Aside from the header & summary, each section is an
+//! ordered selection of master data items (skills, degrees, jobs, or projects).
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel};
+use crate::state::Key;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeaderSection {
+    pub resume: Key,
+    pub name: String,
+}
+
+impl HeaderSection {
+    pub fn new(resume: Key, name: String) -> Self {
+        Self { resume, name }
+    }
+
+    /// Get the header section of the given resume, if it has one.
+    pub async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        let row: Option<(i64, String)> =
+            sqlx::query_as("SELECT id, name FROM HeaderSection WHERE resume_id=$1;")
+                .bind(resume.db_id()?)
+                .fetch_optional(conn)
+                .await?;
+
+        Ok(row.map(|(id, name)| (id, Self::new(*resume, name))))
+    }
+}
+
+#[async_trait]
+impl DbModel for HeaderSection {
+    const TABLE: &'static str = "HeaderSection";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO HeaderSection (id, resume_id, name) VALUES ($1, $2, $3) RETURNING id;",
+        )
+        .bind(id)
+        .bind(self.resume.db_id()?)
+        .bind(&self.name)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE HeaderSection SET resume_id=$1, name=$2 WHERE id=$3;")
+            .bind(self.resume.db_id()?)
+            .bind(&self.name)
+            .bind(id)
+            .execute(conn)
+            .await?;
+
+        updated_one(result, "HeaderSection", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, name) =
+            sqlx::query_as("SELECT resume_id, name FROM HeaderSection WHERE id=$1;")
+                .bind(id)
+                .fetch_one(conn)
+                .await?;
+
+        Ok(Self::new(Key::Db(resume), name))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, i64, String)> =
+            sqlx::query_as("SELECT id, resume_id, name FROM HeaderSection;")
+                .fetch_all(conn)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, name)| (id, Self::new(Key::Db(resume), name)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummarySection {
+    pub resume: Key,
+    pub content: String,
+}
+
+impl SummarySection {
+    pub fn new(resume: Key, content: String) -> Self {
+        Self { resume, content }
+    }
+
+    /// Get the summary section of the given resume, if it has one.
+    pub async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        let row: Option<(i64, String)> =
+            sqlx::query_as("SELECT id, content FROM SummarySection WHERE resume_id=$1;")
+                .bind(resume.db_id()?)
+                .fetch_optional(conn)
+                .await?;
+
+        Ok(row.map(|(id, content)| (id, Self::new(*resume, content))))
+    }
+}
+
+#[async_trait]
+impl DbModel for SummarySection {
+    const TABLE: &'static str = "SummarySection";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO SummarySection (id, resume_id, content) VALUES ($1, $2, $3) RETURNING id;",
+        )
+        .bind(id)
+        .bind(self.resume.db_id()?)
+        .bind(&self.content)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE SummarySection SET resume_id=$1, content=$2 WHERE id=$3;")
+            .bind(self.resume.db_id()?)
+            .bind(&self.content)
+            .bind(id)
+            .execute(conn)
+            .await?;
+
+        updated_one(result, "SummarySection", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, content) =
+            sqlx::query_as("SELECT resume_id, content FROM SummarySection WHERE id=$1;")
+                .bind(id)
+                .fetch_one(conn)
+                .await?;
+
+        Ok(Self::new(Key::Db(resume), content))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, i64, String)> =
+            sqlx::query_as("SELECT id, resume_id, content FROM SummarySection;")
+                .fetch_all(conn)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, content)| (id, Self::new(Key::Db(resume), content)))
+            .collect())
+    }
+}
+
+/// A section made up of an ordered selection of master data items, e.g. the jobs listed in an
+/// [`ExperienceSection`].
+#[async_trait]
+pub trait ListedSection: DbModel + Send {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self;
+    /// Keys for each item in the section, in display order.
+    fn items(&self) -> &[Key];
+    /// Get this section of the given resume, if it has one.
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>>;
+}
+
+/// Table & column names describing how a section's items are stored.
+struct Listing {
+    /// table holding one row per section
+    section: &'static str,
+    /// table joining a section to its items
+    join: &'static str,
+    /// column on `join` referencing an item
+    item: &'static str,
+}
+
+impl Listing {
+    async fn insert(
+        &self,
+        conn: &mut SqliteConnection,
+        id: Option<i64>,
+        resume: &Key,
+        items: &[Key],
+    ) -> anyhow::Result<i64> {
+        let id: i64 = sqlx::query_scalar(&format!(
+            "INSERT INTO {} (id, resume_id) VALUES ($1, $2) RETURNING id;",
+            self.section
+        ))
+        .bind(id)
+        .bind(resume.db_id()?)
+        .fetch_one(&mut *conn)
+        .await?;
+        self.insert_items(conn, id, items).await?;
+
+        Ok(id)
+    }
+
+    async fn update(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+        resume: &Key,
+        items: &[Key],
+    ) -> anyhow::Result<()> {
+        let result = sqlx::query(&format!(
+            "UPDATE {} SET resume_id=$1 WHERE id=$2;",
+            self.section
+        ))
+        .bind(resume.db_id()?)
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+        updated_one(result, self.section, id)?;
+        sqlx::query(&format!("DELETE FROM {} WHERE section_id=$1;", self.join))
+            .bind(id)
+            .execute(&mut *conn)
+            .await?;
+
+        self.insert_items(conn, id, items).await
+    }
+
+    async fn insert_items(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+        items: &[Key],
+    ) -> anyhow::Result<()> {
+        for (position, item) in items.iter().enumerate() {
+            sqlx::query(&format!(
+                "INSERT INTO {} (section_id, {}, position) VALUES ($1, $2, $3);",
+                self.join, self.item
+            ))
+            .bind(id)
+            .bind(item.db_id()?)
+            .bind(position as i64)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn id_for(
+        &self,
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<i64>> {
+        sqlx::query_scalar(&format!(
+            "SELECT id FROM {} WHERE resume_id=$1;",
+            self.section
+        ))
+        .bind(resume.db_id()?)
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn get_one_by_id(
+        &self,
+        conn: &mut SqliteConnection,
+        id: i64,
+    ) -> anyhow::Result<(Key, Vec<Key>)> {
+        let resume: i64 = sqlx::query_scalar(&format!(
+            "SELECT resume_id FROM {} WHERE id=$1;",
+            self.section
+        ))
+        .bind(id)
+        .fetch_one(&mut *conn)
+        .await?;
+        let items: Vec<i64> = sqlx::query_scalar(&format!(
+            "SELECT {} FROM {} WHERE section_id=$1 ORDER BY position;",
+            self.item, self.join
+        ))
+        .bind(id)
+        .fetch_all(conn)
+        .await?;
+
+        Ok((Key::Db(resume), items.into_iter().map(Key::Db).collect()))
+    }
+
+    async fn get_all(
+        &self,
+        conn: &mut SqliteConnection,
+    ) -> anyhow::Result<Vec<(i64, Key, Vec<Key>)>> {
+        let ids: Vec<i64> = sqlx::query_scalar(&format!("SELECT id FROM {};", self.section))
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let mut sections = Vec::with_capacity(ids.len());
+        for id in ids {
+            let (resume, items) = self.get_one_by_id(&mut *conn, id).await?;
+            sections.push((id, resume, items));
+        }
+
+        Ok(sections)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillsSection {
+    pub resume: Key,
+    pub skills: Vec<Key>,
+}
+
+impl SkillsSection {
+    const LISTING: Listing = Listing {
+        section: "SkillsSection",
+        join: "SkillsSectionSkill",
+        item: "skill_id",
+    };
+
+    pub fn new(resume: Key, skills: Vec<Key>) -> Self {
+        Self { resume, skills }
+    }
+}
+
+#[async_trait]
+impl ListedSection for SkillsSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.skills
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for SkillsSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.skills)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.skills)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, skills) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, skills))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, skills)| (id, Self::new(resume, skills)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EducationSection {
+    pub resume: Key,
+    pub degrees: Vec<Key>,
+}
+
+impl EducationSection {
+    const LISTING: Listing = Listing {
+        section: "EducationSection",
+        join: "EducationSectionDegree",
+        item: "degree_id",
+    };
+
+    pub fn new(resume: Key, degrees: Vec<Key>) -> Self {
+        Self { resume, degrees }
+    }
+}
+
+#[async_trait]
+impl ListedSection for EducationSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.degrees
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for EducationSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.degrees)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.degrees)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, degrees) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, degrees))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, degrees)| (id, Self::new(resume, degrees)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExperienceSection {
+    pub resume: Key,
+    pub jobs: Vec<Key>,
+}
+
+impl ExperienceSection {
+    const LISTING: Listing = Listing {
+        section: "ExperienceSection",
+        join: "ExperienceSectionJob",
+        item: "job_id",
+    };
+
+    pub fn new(resume: Key, jobs: Vec<Key>) -> Self {
+        Self { resume, jobs }
+    }
+}
+
+#[async_trait]
+impl ListedSection for ExperienceSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.jobs
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for ExperienceSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.jobs)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.jobs)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, jobs) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, jobs))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, jobs)| (id, Self::new(resume, jobs)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectSection {
+    pub resume: Key,
+    pub projects: Vec<Key>,
+}
+
+impl ProjectSection {
+    const LISTING: Listing = Listing {
+        section: "ProjectSection",
+        join: "ProjectSectionProject",
+        item: "project_id",
+    };
+
+    pub fn new(resume: Key, projects: Vec<Key>) -> Self {
+        Self { resume, projects }
+    }
+}
+
+#[async_trait]
+impl ListedSection for ProjectSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.projects
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for ProjectSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.projects)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.projects)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, projects) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, projects))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, projects)| (id, Self::new(resume, projects)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificationSection {
+    pub resume: Key,
+    pub certifications: Vec<Key>,
+}
+
+impl CertificationSection {
+    const LISTING: Listing = Listing {
+        section: "CertificationSection",
+        join: "CertificationSectionCertification",
+        item: "certification_id",
+    };
+
+    pub fn new(resume: Key, certifications: Vec<Key>) -> Self {
+        Self {
+            resume,
+            certifications,
+        }
+    }
+}
+
+#[async_trait]
+impl ListedSection for CertificationSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.certifications
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for CertificationSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.certifications)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.certifications)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, certifications) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, certifications))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, certifications)| (id, Self::new(resume, certifications)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwardSection {
+    pub resume: Key,
+    pub awards: Vec<Key>,
+}
+
+impl AwardSection {
+    const LISTING: Listing = Listing {
+        section: "AwardSection",
+        join: "AwardSectionAward",
+        item: "award_id",
+    };
+
+    pub fn new(resume: Key, awards: Vec<Key>) -> Self {
+        Self { resume, awards }
+    }
+}
+
+#[async_trait]
+impl ListedSection for AwardSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.awards
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for AwardSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.awards)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.awards)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, awards) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, awards))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, awards)| (id, Self::new(resume, awards)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicationSection {
+    pub resume: Key,
+    pub publications: Vec<Key>,
+}
+
+impl PublicationSection {
+    const LISTING: Listing = Listing {
+        section: "PublicationSection",
+        join: "PublicationSectionPublication",
+        item: "publication_id",
+    };
+
+    pub fn new(resume: Key, publications: Vec<Key>) -> Self {
+        Self {
+            resume,
+            publications,
+        }
+    }
+}
+
+#[async_trait]
+impl ListedSection for PublicationSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.publications
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for PublicationSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.publications)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.publications)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, publications) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, publications))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, publications)| (id, Self::new(resume, publications)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolunteerSection {
+    pub resume: Key,
+    pub volunteering: Vec<Key>,
+}
+
+impl VolunteerSection {
+    const LISTING: Listing = Listing {
+        section: "VolunteerSection",
+        join: "VolunteerSectionVolunteer",
+        item: "volunteer_id",
+    };
+
+    pub fn new(resume: Key, volunteering: Vec<Key>) -> Self {
+        Self {
+            resume,
+            volunteering,
+        }
+    }
+}
+
+#[async_trait]
+impl ListedSection for VolunteerSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.volunteering
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for VolunteerSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.volunteering)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.volunteering)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, volunteering) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, volunteering))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, volunteering)| (id, Self::new(resume, volunteering)))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageSection {
+    pub resume: Key,
+    pub languages: Vec<Key>,
+}
+
+impl LanguageSection {
+    const LISTING: Listing = Listing {
+        section: "LanguageSection",
+        join: "LanguageSectionLanguage",
+        item: "language_id",
+    };
+
+    pub fn new(resume: Key, languages: Vec<Key>) -> Self {
+        Self { resume, languages }
+    }
+}
+
+#[async_trait]
+impl ListedSection for LanguageSection {
+    fn with_items(resume: Key, items: Vec<Key>) -> Self {
+        Self::new(resume, items)
+    }
+
+    fn items(&self) -> &[Key] {
+        &self.languages
+    }
+
+    async fn get_one_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        match Self::LISTING.id_for(&mut *conn, resume).await? {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl DbModel for LanguageSection {
+    const TABLE: &'static str = Self::LISTING.section;
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        Self::LISTING
+            .insert(conn, id, &self.resume, &self.languages)
+            .await
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        Self::LISTING
+            .update(conn, id, &self.resume, &self.languages)
+            .await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (resume, languages) = Self::LISTING.get_one_by_id(conn, id).await?;
+
+        Ok(Self::new(resume, languages))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows = Self::LISTING.get_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, resume, languages)| (id, Self::new(resume, languages)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{test_pool, Resume, Skill};
+
+    #[tokio::test]
+    async fn listed_sections_keep_item_order() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let resume = Resume::new("Mine".into()).create(&mut conn).await.unwrap();
+        let mut skills = Vec::new();
+        for name in ["Rust", "Go", "C"] {
+            let id = Skill::new(name.into(), None)
+                .create(&mut conn)
+                .await
+                .unwrap();
+            skills.push(Key::Db(id));
+        }
+        skills.reverse();
+
+        let section = SkillsSection::new(Key::Db(resume), skills);
+        let id = section.create(&mut conn).await.unwrap();
+
+        assert_eq!(
+            SkillsSection::get_one_by_id(&mut conn, id).await.unwrap(),
+            section
+        );
+    }
+
+    #[tokio::test]
+    async fn a_resume_has_at_most_one_of_each_section() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let resume = Key::Db(Resume::new("Mine".into()).create(&mut conn).await.unwrap());
+
+        let first = SummarySection::new(resume, "First".into());
+        let second = SummarySection::new(resume, "Second".into());
+
+        assert!(first.create(&mut conn).await.is_ok());
+        assert!(second.create(&mut conn).await.is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel, DescriptionItem};
+use crate::state::Key;
+
+/// A [`DescriptionItem`] chosen to be shown on a resume.
+///
+/// Once a resume selects any bullets for a job or project, only the selected ones are shown for
+/// it, in ascending order of `position`. Jobs & projects without any selected bullets show all of
+/// their bullets, as described by the master records.
+///
+/// A selected bullet shows it's override `text` if it has one, otherwise the chosen variant of
+/// the item, falling back to the item's own text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectedBullet {
+    pub resume: Key,
+    pub item: Key,
+    pub position: i64,
+    /// Label of the item's [`Variant`](super::Variant) to show on this resume
+    pub variant: Option<String>,
+    /// Text to show on this resume in place of the item's own text
+    pub text: Option<String>,
+}
+
+impl SelectedBullet {
+    pub fn new(resume: Key, item: Key, position: i64) -> Self {
+        Self {
+            resume,
+            item,
+            position,
+            variant: None,
+            text: None,
+        }
+    }
+
+    /// The text this resume shows for the selected item.
+    pub fn text_for<'a>(&'a self, item: &'a DescriptionItem) -> &'a str {
+        let variant = self
+            .variant
+            .as_deref()
+            .and_then(|label| item.variant(label))
+            .map(|variant| variant.text.as_str());
+
+        self.text.as_deref().or(variant).unwrap_or(&item.text)
+    }
+
+    /// Get every bullet selected for the given resume, in display order.
+    pub async fn get_all_for(
+        conn: &mut SqliteConnection,
+        resume: &Key,
+    ) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<Row> = sqlx::query_as(&format!(
+            "SELECT {COLUMNS} FROM SelectedBullet WHERE resume_id=$1 ORDER BY position, id;"
+        ))
+        .bind(resume.db_id()?)
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows.into_iter().map(Self::from_row).collect())
+    }
+
+    fn from_row((id, resume, item, position, variant, text): Row) -> (i64, Self) {
+        (
+            id,
+            Self {
+                resume: Key::Db(resume),
+                item: Key::Db(item),
+                position,
+                variant,
+                text,
+            },
+        )
+    }
+}
+
+const COLUMNS: &str = "id, resume_id, item_id, position, variant, override_text";
+type Row = (i64, i64, i64, i64, Option<String>, Option<String>);
+
+#[async_trait]
+impl DbModel for SelectedBullet {
+    const TABLE: &'static str = "SelectedBullet";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO SelectedBullet (id, resume_id, item_id, position, variant, override_text)
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING id;",
+        )
+        .bind(id)
+        .bind(self.resume.db_id()?)
+        .bind(self.item.db_id()?)
+        .bind(self.position)
+        .bind(&self.variant)
+        .bind(&self.text)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE SelectedBullet SET resume_id=$1, item_id=$2, position=$3, variant=$4, override_text=$5 WHERE id=$6;")
+        .bind(self.resume.db_id()?)
+        .bind(self.item.db_id()?)
+        .bind(self.position)
+        .bind(&self.variant)
+        .bind(&self.text)
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+        updated_one(result, "SelectedBullet", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let row: Row = sqlx::query_as(&format!(
+            "SELECT {COLUMNS} FROM SelectedBullet WHERE id=$1;"
+        ))
+        .bind(id)
+        .fetch_one(conn)
+        .await?;
+
+        Ok(Self::from_row(row).1)
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<Row> = sqlx::query_as(&format!(
+            "SELECT {COLUMNS} FROM SelectedBullet ORDER BY resume_id, position, id;"
+        ))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows.into_iter().map(Self::from_row).collect())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn override_text_beats_chosen_variant_beats_own_text() {
+    use super::{Parent, Variant};
+
+    let mut item = DescriptionItem::new(Parent::Job(Key::Db(1)), 0, "Cut costs".into());
+    item.variants
+        .push(Variant::new("short".into(), "Saved $1M".into()));
+    let mut selection = SelectedBullet::new(Key::Db(1), Key::Db(1), 0);
+    assert_eq!(selection.text_for(&item), "Cut costs");
+
+    selection.variant = Some("Short".into());
+    assert_eq!(selection.text_for(&item), "Saved $1M");
+
+    selection.text = Some("Saved a million dollars".into());
+    assert_eq!(selection.text_for(&item), "Saved a million dollars");
+}
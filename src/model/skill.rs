@@ -1,51 +1,536 @@
 use async_trait::async_trait;
-use sqlx::{query_as, SqlitePool};
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
 
-use super::{Id, Model};
+use super::{updated_one, DbModel};
+use crate::state::Key;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Skill {
-    pub id: i64,
     pub name: String,
+    pub category: Option<Key>,
+    /// Other names the skill goes by, e.g. JS for JavaScript
+    ///
+    /// Lookups by name resolve aliases to this skill, so no alias may also be the name or alias
+    /// of another skill.
+    pub aliases: Vec<String>,
 }
 
-impl Id<i64> for Skill {
-    fn id_field(&self) -> &i64 {
-        &self.id
+impl Skill {
+    pub fn new(name: String, category: Option<Key>) -> Self {
+        Self {
+            name,
+            category,
+            aliases: Vec::new(),
+        }
+    }
+
+    async fn insert_aliases(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        for alias in &self.aliases {
+            sqlx::query("INSERT INTO SkillAlias (skill_id, name) VALUES ($1, $2);")
+                .bind(id)
+                .bind(alias)
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Check if the skill goes by the given name, either as it's name or an alias, ignoring case.
+    pub fn is_called(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+
+        self.name.to_lowercase() == name || self.aliases.iter().any(|a| a.to_lowercase() == name)
+    }
+
+    /// Check if the given text mentions the skill by name or alias, as a whole word & ignoring
+    /// case.
+    pub fn is_mentioned_in(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+
+        std::iter::once(&self.name)
+            .chain(&self.aliases)
+            .map(|name| name.to_lowercase())
+            .any(|name| {
+                text.match_indices(&name).any(|(at, _)| {
+                    !is_word_char(text[..at].chars().next_back())
+                        && !is_word_char(text[at + name.len()..].chars().next())
+                })
+            })
+    }
+
+    /// Get the skill with exactly the given name or alias, ignoring case, if there is one.
+    pub async fn get_one_by_name(
+        conn: &mut SqliteConnection,
+        name: &str,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        let id: Option<i64> = sqlx::query_scalar(
+            "SELECT id FROM Skill WHERE name=$1 COLLATE NOCASE
+             UNION ALL
+             SELECT skill_id FROM SkillAlias WHERE name=$1;",
+        )
+        .bind(name)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        match id {
+            Some(id) => Ok(Some((id, Self::get_one_by_id(conn, id).await?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the first skill with a name or alias containing the given query, preferring matches
+    /// on a skill's name over it's aliases.
+    pub async fn get_one_includes(
+        conn: &mut SqliteConnection,
+        query: &str,
+    ) -> anyhow::Result<(i64, Self)> {
+        let q = format!("%{query}%");
+        let id: i64 = sqlx::query_scalar(
+            "SELECT id FROM (
+                 SELECT id, 0 AS rank FROM Skill WHERE name LIKE $1
+                 UNION ALL
+                 SELECT skill_id, 1 AS rank FROM SkillAlias WHERE name LIKE $1
+             )
+             ORDER BY rank LIMIT 1;",
+        )
+        .bind(q)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok((id, Self::get_one_by_id(conn, id).await?))
+    }
+}
+
+#[async_trait]
+impl DbModel for Skill {
+    const TABLE: &'static str = "Skill";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let category = self.category.as_ref().map(Key::db_id).transpose()?;
+        let id = sqlx::query_scalar(
+            "INSERT INTO Skill (id, name, category_id) VALUES ($1, $2, $3) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.name)
+        .bind(category)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        self.insert_aliases(conn, id).await?;
+
+        Ok(id)
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let category = self.category.as_ref().map(Key::db_id).transpose()?;
+        // clear out old aliases first so the skill can be renamed to one of them
+        sqlx::query("DELETE FROM SkillAlias WHERE skill_id=$1;")
+            .bind(id)
+            .execute(&mut *conn)
+            .await?;
+        let result = sqlx::query("UPDATE Skill SET name=$1, category_id=$2 WHERE id=$3;")
+            .bind(&self.name)
+            .bind(category)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?;
+        updated_one(result, "Skill", id)?;
+
+        self.insert_aliases(conn, id).await
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (name, category): (String, Option<i64>) =
+            sqlx::query_as("SELECT name, category_id FROM Skill WHERE id=$1;")
+                .bind(id)
+                .fetch_one(&mut *conn)
+                .await?;
+        let aliases =
+            sqlx::query_scalar("SELECT name FROM SkillAlias WHERE skill_id=$1 ORDER BY id;")
+                .bind(id)
+                .fetch_all(conn)
+                .await?;
+
+        Ok(Self {
+            name,
+            category: category.map(Key::Db),
+            aliases,
+        })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM Skill ORDER BY name;")
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let mut skills = Vec::with_capacity(ids.len());
+        for id in ids {
+            skills.push((id, Self::get_one_by_id(&mut *conn, id).await?));
+        }
+
+        Ok(skills)
+    }
+}
+
+/// A named grouping of skills.
+///
+/// Categories nest under an optional parent to form a taxonomy, e.g. Languages > Systems, with
+/// each [`Skill`] belonging to at most one category anywhere in the tree. Names are unique across
+/// the whole taxonomy, so a category can always be referred to by name alone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillCategory {
+    pub name: String,
+    pub parent: Option<Key>,
+}
+
+impl SkillCategory {
+    pub fn new(name: String, parent: Option<Key>) -> Self {
+        Self { name, parent }
+    }
+
+    /// Get the category with exactly the given name, ignoring case, if there is one.
+    pub async fn get_one_by_name(
+        conn: &mut SqliteConnection,
+        name: &str,
+    ) -> anyhow::Result<Option<(i64, Self)>> {
+        let row: Option<(i64, String, Option<i64>)> = sqlx::query_as(
+            "SELECT id, name, parent_id FROM SkillCategory WHERE name=$1 COLLATE NOCASE;",
+        )
+        .bind(name)
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(row.map(|(id, name, parent)| (id, Self::new(name, parent.map(Key::Db)))))
+    }
+
+    /// Get the names of every category from the root of the taxonomy down to the given one.
+    pub async fn path(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Vec<String>> {
+        sqlx::query_scalar(
+            "WITH RECURSIVE ancestor (id, name, parent_id, depth) AS (
+                 SELECT id, name, parent_id, 0 FROM SkillCategory WHERE id=$1
+                 UNION ALL
+                 SELECT c.id, c.name, c.parent_id, a.depth + 1
+                 FROM SkillCategory c JOIN ancestor a ON c.id = a.parent_id
+             )
+             SELECT name FROM ancestor ORDER BY depth DESC;",
+        )
+        .bind(id)
+        .fetch_all(conn)
+        .await
+        .map_err(|e| e.into())
     }
 }
 
 #[async_trait]
-impl Model<i64> for Skill {
-    type Output = Self;
+impl DbModel for SkillCategory {
+    const TABLE: &'static str = "SkillCategory";
 
-    async fn create(pool: &SqlitePool, name: &String) -> anyhow::Result<Self::Output> {
-        query_as!(
-            Skill,
-            "INSERT INTO Skill (name) Values ($1) RETURNING name, id;",
-            name
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        let parent = self.parent.as_ref().map(Key::db_id).transpose()?;
+        sqlx::query_scalar(
+            "INSERT INTO SkillCategory (id, name, parent_id) VALUES ($1, $2, $3) RETURNING id;",
         )
-        .fetch_one(pool)
+        .bind(id)
+        .bind(&self.name)
+        .bind(parent)
+        .fetch_one(conn)
         .await
         .map_err(|e| e.into())
     }
 
-    async fn get_one_by_id(pool: &SqlitePool, id: &i64) -> anyhow::Result<Self::Output> {
-        query_as!(Skill, "SELECT * FROM Skill WHERE id=$1;", id)
-            .fetch_one(pool)
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let parent = self.parent.as_ref().map(Key::db_id).transpose()?;
+        let result = sqlx::query("UPDATE SkillCategory SET name=$1, parent_id=$2 WHERE id=$3;")
+            .bind(&self.name)
+            .bind(parent)
+            .bind(id)
+            .execute(conn)
+            .await?;
+
+        updated_one(result, "SkillCategory", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (name, parent): (String, Option<i64>) =
+            sqlx::query_as("SELECT name, parent_id FROM SkillCategory WHERE id=$1;")
+                .bind(id)
+                .fetch_one(conn)
+                .await?;
+
+        Ok(Self::new(name, parent.map(Key::Db)))
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let rows: Vec<(i64, String, Option<i64>)> =
+            sqlx::query_as("SELECT id, name, parent_id FROM SkillCategory ORDER BY name;")
+                .fetch_all(conn)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name, parent)| (id, Self::new(name, parent.map(Key::Db))))
+            .collect())
+    }
+}
+
+/// `SkillDeveloper`'s job, project, degree & course columns, exactly one of which is set.
+type DeveloperRow = (Option<i64>, Option<i64>, Option<i64>, Option<i64>);
+
+/// Anything a [`Skill`] can be developed through.
+///
+/// Each one is given a row in the `SkillDeveloper` table, which the `DevelopedSkill` table links
+/// skills against.
+///
+/// Ordering sorts developers by kind, in the order listed here, then by id.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Developer {
+    Job(i64),
+    Project(i64),
+    Degree(i64),
+    Course(i64),
+}
+
+impl Developer {
+    /// The `SkillDeveloper` column referencing this developer's row.
+    fn column(&self) -> &'static str {
+        match self {
+            Developer::Job(_) => "job_id",
+            Developer::Project(_) => "project_id",
+            Developer::Degree(_) => "degree_id",
+            Developer::Course(_) => "course_id",
+        }
+    }
+
+    fn id(&self) -> i64 {
+        match self {
+            Developer::Job(id)
+            | Developer::Project(id)
+            | Developer::Degree(id)
+            | Developer::Course(id) => *id,
+        }
+    }
+
+    /// Get every developer linked to the skill with the given id, sorted by kind.
+    pub async fn all_for(conn: &mut SqliteConnection, skill: i64) -> anyhow::Result<Vec<Self>> {
+        let rows: Vec<DeveloperRow> = sqlx::query_as(
+            "SELECT sd.job_id, sd.project_id, sd.degree_id, sd.course_id FROM SkillDeveloper sd
+             JOIN DevelopedSkill ds ON ds.dev_id = sd.id
+             WHERE ds.skill_id=$1;",
+        )
+        .bind(skill)
+        .fetch_all(conn)
+        .await?;
+
+        let mut developers = rows
+            .into_iter()
+            .map(|row| match row {
+                (Some(id), None, None, None) => Ok(Developer::Job(id)),
+                (None, Some(id), None, None) => Ok(Developer::Project(id)),
+                (None, None, Some(id), None) => Ok(Developer::Degree(id)),
+                (None, None, None, Some(id)) => Ok(Developer::Course(id)),
+                _ => Err(anyhow::anyhow!(
+                    "SkillDeveloper must reference exactly one Job, Project, Degree, or Course"
+                )),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        developers.sort();
+
+        Ok(developers)
+    }
+
+    /// Register this developer & link it to each of the given skills.
+    pub(crate) async fn link_skills(
+        &self,
+        conn: &mut SqliteConnection,
+        skills: &[Key],
+    ) -> anyhow::Result<()> {
+        let dev_id: i64 = sqlx::query_scalar(&format!(
+            "INSERT INTO SkillDeveloper ({}) VALUES ($1) RETURNING id;",
+            self.column()
+        ))
+        .bind(self.id())
+        .fetch_one(&mut *conn)
+        .await?;
+
+        for skill in skills {
+            sqlx::query("INSERT INTO DevelopedSkill (skill_id, dev_id) VALUES ($1, $2);")
+                .bind(skill.db_id()?)
+                .bind(dev_id)
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Replace whatever skills are linked to this developer with the given ones.
+    pub(crate) async fn relink_skills(
+        &self,
+        conn: &mut SqliteConnection,
+        skills: &[Key],
+    ) -> anyhow::Result<()> {
+        // removing the developer's row removes it's links too
+        sqlx::query(&format!(
+            "DELETE FROM SkillDeveloper WHERE {}=$1;",
+            self.column()
+        ))
+        .bind(self.id())
+        .execute(&mut *conn)
+        .await?;
+
+        self.link_skills(conn, skills).await
+    }
+
+    /// Get keys for every skill linked to this developer.
+    pub(crate) async fn skills(&self, conn: &mut SqliteConnection) -> anyhow::Result<Vec<Key>> {
+        let ids: Vec<i64> = sqlx::query_scalar(&format!(
+            "SELECT ds.skill_id FROM DevelopedSkill ds
+             JOIN SkillDeveloper sd ON sd.id = ds.dev_id
+             WHERE sd.{}=$1
+             ORDER BY ds.rowid;",
+            self.column()
+        ))
+        .bind(self.id())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ids.into_iter().map(Key::Db).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::test_pool;
+
+    #[test]
+    fn mentions_must_be_whole_words() {
+        let mut go = Skill::new("Go".into(), None);
+        go.aliases = vec!["Golang".into()];
+
+        assert!(go.is_mentioned_in("Rewrote the scheduler in go."));
+        assert!(go.is_mentioned_in("Ported services to golang & Rust"));
+        assert!(!go.is_mentioned_in("Improved good governance"));
+    }
+
+    #[tokio::test]
+    async fn category_path_runs_from_root_down() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let languages = SkillCategory::new("Languages".into(), None)
+            .create(&mut conn)
             .await
-            .map_err(|e| e.into())
+            .unwrap();
+        let systems = SkillCategory::new("Systems".into(), Some(Key::Db(languages)))
+            .create(&mut conn)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            SkillCategory::path(&mut conn, systems).await.unwrap(),
+            vec!["Languages".to_string(), "Systems".to_string()]
+        );
     }
 
-    async fn get_one_includes(pool: &SqlitePool, query: &String) -> anyhow::Result<Self::Output> {
-        let q = format!("%{query}%");
-        query_as!(Skill, "SELECT * FROM Skill WHERE name LIKE $1", q)
-            .fetch_one(pool)
+    #[tokio::test]
+    async fn deleting_a_category_leaves_its_skills_uncategorized() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let languages = SkillCategory::new("Languages".into(), None)
+            .create(&mut conn)
+            .await
+            .unwrap();
+        let systems = SkillCategory::new("Systems".into(), Some(Key::Db(languages)))
+            .create(&mut conn)
+            .await
+            .unwrap();
+        let rust = Skill::new("Rust".into(), Some(Key::Db(systems)))
+            .create(&mut conn)
+            .await
+            .unwrap();
+
+        sqlx::query("DELETE FROM SkillCategory WHERE id=$1;")
+            .bind(languages)
+            .execute(&mut *conn)
             .await
-            .map_err(|e| e.into())
+            .unwrap();
+
+        assert!(SkillCategory::get_all(&mut conn).await.unwrap().is_empty());
+        assert_eq!(
+            Skill::get_one_by_id(&mut conn, rust).await.unwrap(),
+            Skill::new("Rust".into(), None)
+        );
+    }
+
+    #[tokio::test]
+    async fn lookups_resolve_aliases_to_the_skill() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let mut javascript = Skill::new("JavaScript".into(), None);
+        javascript.aliases = vec!["JS".into(), "ECMAScript".into()];
+        let id = javascript.create(&mut conn).await.unwrap();
+
+        assert_eq!(
+            Skill::get_one_by_name(&mut conn, "js").await.unwrap(),
+            Some((id, javascript.clone()))
+        );
+        assert_eq!(
+            Skill::get_one_includes(&mut conn, "ecma").await.unwrap(),
+            (id, javascript)
+        );
     }
 
-    async fn get_all(pool: &SqlitePool) -> anyhow::Result<Vec<Self::Output>> {
-        todo!()
+    #[tokio::test]
+    async fn a_name_belongs_to_only_one_skill() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let mut javascript = Skill::new("JavaScript".into(), None);
+        javascript.aliases = vec!["JS".into()];
+        javascript.create(&mut conn).await.unwrap();
+
+        let mut java = Skill::new("Java".into(), None);
+        java.aliases = vec!["javascript".into()];
+
+        assert!(Skill::new("js".into(), None)
+            .create(&mut conn)
+            .await
+            .is_err());
+        assert!(java.create(&mut conn).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn developers_of_a_skill_are_sorted_by_kind() {
+        let pool = test_pool().await;
+        let mut conn = pool.acquire().await.unwrap();
+        let rust = Key::Db(
+            Skill::new("Rust".into(), None)
+                .create(&mut conn)
+                .await
+                .unwrap(),
+        );
+        let project = crate::model::Project::new("res-gen".into(), vec![rust])
+            .create(&mut conn)
+            .await
+            .unwrap();
+        let job = crate::model::Job::new(
+            "Acme".into(),
+            "Engineer".into(),
+            "2020-01-01".parse().unwrap(),
+            None,
+            vec![rust],
+        )
+        .create(&mut conn)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Developer::all_for(&mut conn, rust.db_id().unwrap())
+                .await
+                .unwrap(),
+            vec![Developer::Job(job), Developer::Project(project)]
+        );
     }
 }
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+
+use super::{updated_one, DbModel};
+
+/// Unpaid work done for an organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Volunteer {
+    pub organization: String,
+    pub role: String,
+    pub start: NaiveDate,
+    /// Last day volunteering, `None` if still ongoing
+    pub end: Option<NaiveDate>,
+    pub description: Option<String>,
+}
+
+impl Volunteer {
+    pub fn new(organization: String, role: String, start: NaiveDate) -> Self {
+        Self {
+            organization,
+            role,
+            start,
+            end: None,
+            description: None,
+        }
+    }
+}
+
+type Row = (String, String, NaiveDate, Option<NaiveDate>, Option<String>);
+
+#[async_trait]
+impl DbModel for Volunteer {
+    const TABLE: &'static str = "Volunteer";
+
+    async fn insert(&self, conn: &mut SqliteConnection, id: Option<i64>) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "INSERT INTO Volunteer (id, organization, role, start_date, end_date, description)
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING id;",
+        )
+        .bind(id)
+        .bind(&self.organization)
+        .bind(&self.role)
+        .bind(self.start)
+        .bind(self.end)
+        .bind(&self.description)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update(&self, conn: &mut SqliteConnection, id: i64) -> anyhow::Result<()> {
+        let result = sqlx::query("UPDATE Volunteer SET organization=$1, role=$2, start_date=$3, end_date=$4, description=$5 WHERE id=$6;")
+        .bind(&self.organization)
+        .bind(&self.role)
+        .bind(self.start)
+        .bind(self.end)
+        .bind(&self.description)
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+        updated_one(result, "Volunteer", id)
+    }
+
+    async fn get_one_by_id(conn: &mut SqliteConnection, id: i64) -> anyhow::Result<Self> {
+        let (organization, role, start, end, description): Row = sqlx::query_as(
+            "SELECT organization, role, start_date, end_date, description
+             FROM Volunteer WHERE id=$1;",
+        )
+        .bind(id)
+        .fetch_one(conn)
+        .await?;
+
+        Ok(Self {
+            organization,
+            role,
+            start,
+            end,
+            description,
+        })
+    }
+
+    async fn get_all(conn: &mut SqliteConnection) -> anyhow::Result<Vec<(i64, Self)>> {
+        let ids: Vec<i64> =
+            sqlx::query_scalar("SELECT id FROM Volunteer ORDER BY start_date DESC, id;")
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut volunteering = Vec::with_capacity(ids.len());
+        for id in ids {
+            volunteering.push((id, Self::get_one_by_id(&mut *conn, id).await?));
+        }
+
+        Ok(volunteering)
+    }
+}
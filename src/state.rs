@@ -1,28 +1,215 @@
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+};
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
 
 use crate::model;
+use crate::ttuple::{self, Fold, Func, Here, Map, Nil, There, Ttuple};
+
+/// Lay out the registry of caches state is made of from a list of the types they hold, giving
+/// each type the path to it's cache.
+macro_rules! registry {
+    (@list) => { Nil };
+    (@list $head:ty $(, $tail:ty)*) => {
+        Ttuple<TempCache<$head>, registry!(@list $($tail),*)>
+    };
+    (@index $index:ty;) => {};
+    (@index $index:ty; $head:ty $(, $tail:ty)*) => {
+        impl Registered for $head {
+            type Index = $index;
+        }
+        registry!(@index There<$index>; $($tail),*);
+    };
+    ($($model:ty),+ $(,)?) => {
+        /// A cache for each type of value state holds.
+        type Registry = registry!(@list $($model),+);
+        registry!(@index Here; $($model),+);
+    };
+}
+
+// registering a type here gives state a cache for it, along with adding, replacing & getting it
+registry! {
+    model::Resume,
+    model::Contact,
+    model::Skill,
+    model::Job,
+    model::DescriptionItem,
+    model::Degree,
+    model::Course,
+    model::Project,
+    model::HeaderSection,
+    model::SkillCategory,
+    model::Certification,
+    model::Award,
+    model::Publication,
+    model::Volunteer,
+    model::Language,
+    model::SummarySection,
+    model::SkillsSection,
+    model::EducationSection,
+    model::ExperienceSection,
+    model::ProjectSection,
+    model::CertificationSection,
+    model::AwardSection,
+    model::PublicationSection,
+    model::VolunteerSection,
+    model::LanguageSection,
+    model::SelectedBullet,
+}
+
+/// A type of value state has a cache for.
+pub trait Registered {
+    /// Where the type's cache is in the registry.
+    type Index;
+}
 
 /// The basic structure of all data for entire app.
 ///
-/// Everything fits into one of a few specified data types, each with it's own cache.
+/// Everything fits into one of a few specified data types, each with it's own cache, all kept
+/// together in a [`Registry`].
 #[derive(Debug, Clone)]
 pub struct AppState {
-    resume: TempCache<model::Resume>,
-    contact: TempCache<model::Contact>,
+    caches: Registry,
 }
 
 impl AppState {
     pub fn new() -> Self {
         Self {
-            resume: TempCache::new(),
-            contact: TempCache::new(),
+            caches: Registry::default(),
         }
     }
+
+    /// Borrow the cache holding every `V`, for working on a type that can't be inferred.
+    fn cache_of<V>(&mut self) -> &mut TempCache<V>
+    where
+        Self: Caches<V>,
+    {
+        self.cache_mut()
+    }
+
+    /// Remove the most recently added `V`, freeing it's temp key up for the next one added.
+    pub fn unadd<V>(&mut self) -> Key
+    where
+        Self: Caches<V>,
+    {
+        self.cache_mut().unadd()
+    }
+
+    /// Use up the next temp key for a `V` without adding anything under it.
+    pub fn reserve<V>(&mut self) -> Key
+    where
+        Self: Caches<V>,
+    {
+        self.cache_mut().reserve()
+    }
+
+    /// Look up a `V` already in state, without falling back to anywhere else.
+    pub fn cached<V>(&self, key: &Key) -> Option<&V>
+    where
+        Self: Caches<V>,
+    {
+        self.cache().cache.get(key)
+    }
+
+    /// Every value held that differs from an earlier state, as the name of it's type, it's key &
+    /// how it changed.
+    ///
+    /// Only values held in state can be compared, so anything a change reached without loading it,
+    /// e.g. rows the db deletes along with their parent, isn't included.
+    pub fn diff(&self, earlier: &AppState) -> Vec<(&'static str, Key, Status)> {
+        (&self.caches).fold(Vec::new(), DiffCache(earlier))
+    }
+
+    /// Remove each of the given values, along with anything depending on them in turn.
+    fn remove_each<V>(&mut self, keys: Vec<Key>)
+    where
+        Self: Remove<V, Key>,
+    {
+        for key in keys {
+            Remove::<V, Key>::remove(self, key);
+        }
+    }
+
+    /// Put a value back under the given key, or forget the key entirely if given no value.
+    pub fn restore<V>(&mut self, key: Key, value: Option<V>) -> Key
+    where
+        Self: Caches<V>,
+    {
+        let cache = self.cache_mut();
+        match value {
+            Some(value) => {
+                cache.replace(key, value);
+            }
+            None => {
+                cache.cache.remove(&key);
+            }
+        };
+        key
+    }
+}
+
+/// Borrow the cache holding every `V` in state.
+pub trait Caches<V> {
+    fn cache(&self) -> &TempCache<V>;
+    fn cache_mut(&mut self) -> &mut TempCache<V>;
+}
+
+impl<V> Caches<V> for AppState
+where
+    V: Registered,
+    Registry: ttuple::Get<TempCache<V>, V::Index>,
+{
+    fn cache(&self) -> &TempCache<V> {
+        ttuple::Get::get(&self.caches)
+    }
+
+    fn cache_mut(&mut self) -> &mut TempCache<V> {
+        ttuple::Get::get_mut(&mut self.caches)
+    }
+}
+
+/// Rewrites every key in each cache it's called with, & every key held by a cached value.
+struct RekeyCache<'k>(&'k KeyMap);
+
+impl<V> Func<&mut TempCache<V>> for RekeyCache<'_>
+where
+    V: Rekey + 'static,
+{
+    type Output = ();
+
+    fn call(&mut self, cache: &mut TempCache<V>) {
+        cache.rekey(self.0);
+    }
+}
+
+/// Collects every value held in each cache it's called with that differs from the same cache in
+/// an earlier state.
+struct DiffCache<'s>(&'s AppState);
+
+impl<V> Func<(Vec<(&'static str, Key, Status)>, &TempCache<V>)> for DiffCache<'_>
+where
+    V: model::DbModel + PartialEq,
+    AppState: Caches<V>,
+{
+    type Output = Vec<(&'static str, Key, Status)>;
+
+    fn call(
+        &mut self,
+        (mut diff, cache): (Vec<(&'static str, Key, Status)>, &TempCache<V>),
+    ) -> Self::Output {
+        cache.diff(self.0.cache(), &mut diff);
+        diff
+    }
 }
 
 // create a single type to encapsulate all State behaviours
+#[allow(dead_code)]
 pub trait State<Val, Idx>: AddNew<Val, Idx> + Get<Val, Idx> {}
 
 // then impl that type automatically for anything that impls all the behaviours
@@ -34,55 +221,582 @@ impl<S, Val, Idx> State<Val, Idx> for S where S: AddNew<Val, Idx> + Get<Val, Idx
 /// By differentiating between temporary index values & those from the DB, key collisions
 /// are eliminated, yet items are allowed to exist in the cache that haven't been saved to the DB
 /// yet without having to know all possible keys that might be in the DB already.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Key {
     Tmp(i64),
     Db(i64),
 }
 
+/// Keys display as the db id they refer to, so users can pass them to later commands, or as a
+/// placeholder for values that aren't saved yet.
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Db(id) => write!(f, "{id}"),
+            Key::Tmp(n) => write!(f, "(unsaved #{n})"),
+        }
+    }
+}
+
+impl Key {
+    /// Get the database id this key refers to, failing if it refers to an unsaved value instead.
+    pub fn db_id(&self) -> anyhow::Result<i64> {
+        match self {
+            Key::Db(id) => Ok(*id),
+            Key::Tmp(_) => anyhow::bail!("{self:?} refers to a value that hasn't been saved yet"),
+        }
+    }
+}
+
+/// How a value differs from an earlier state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Added,
+    Modified,
+    Deleted,
+}
+
 /// Add a new value to an implementing structure, returning the values new id number.
 pub trait AddNew<Val, Idx> {
     fn add_new(&mut self, value: Val) -> Idx;
 }
 
+/// Replace the value stored under an existing key with a new one, returning the key.
+pub trait Replace<Val, Idx> {
+    fn replace(&mut self, key: Idx, value: Val) -> Idx;
+}
+
+/// Remove the value stored under a key, along with anything held that can't exist without it,
+/// returning the key.
+///
+/// What goes with it matches what the db deletes along with it's row, e.g. removing a job removes
+/// it's bullets & drops it from any experience sections listing it.
+pub trait Remove<Val, Idx> {
+    fn remove(&mut self, key: Idx) -> Idx;
+}
+
 /// Get a value matching the corresponding key & data type, if it exists.
+///
+/// Values not already held may be loaded through the given connection, & are kept for next time.
 #[async_trait]
 pub trait Get<Val, Idx> {
-    async fn get(&self, key: &Idx) -> Option<&Val>;
+    async fn get(&mut self, conn: &mut SqliteConnection, key: &Idx)
+        -> anyhow::Result<Option<&Val>>;
 }
 
-impl AddNew<model::Resume, Key> for AppState {
-    fn add_new(&mut self, value: model::Resume) -> Key {
-        self.resume.add_new(value)
+impl<V> AddNew<V, Key> for AppState
+where
+    Self: Caches<V>,
+{
+    fn add_new(&mut self, value: V) -> Key {
+        self.cache_mut().add_new(value)
     }
 }
 
-impl AddNew<model::Contact, Key> for AppState {
-    fn add_new(&mut self, value: model::Contact) -> Key {
-        self.contact.add_new(value)
+impl<V> Replace<V, Key> for AppState
+where
+    Self: Caches<V>,
+{
+    fn replace(&mut self, key: Key, value: V) -> Key {
+        self.cache_mut().replace(key, value)
     }
 }
 
 #[async_trait]
-impl Get<model::Resume, Key> for AppState {
-    async fn get(&self, key: &Key) -> Option<&model::Resume> {
-        self.resume.get(key).await
+impl<V> Get<V, Key> for AppState
+where
+    V: model::DbModel + Send + Sync,
+    Self: Caches<V>,
+{
+    async fn get(&mut self, conn: &mut SqliteConnection, key: &Key) -> anyhow::Result<Option<&V>> {
+        self.cache_mut().get(conn, key).await
     }
 }
 
-#[async_trait]
-impl Get<model::Contact, Key> for AppState {
-    async fn get(&self, key: &Key) -> Option<&model::Contact> {
-        self.contact.get(key).await
+/// Drop a key from a list of keys, e.g. when the value it refers to is removed.
+fn unlist(keys: &mut Vec<Key>, key: Key) {
+    keys.retain(|listed| *listed != key);
+}
+
+impl Remove<model::Resume, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Resume>().forget(&key);
+        let headers = self
+            .cache_of::<model::HeaderSection>()
+            .forget_where(|header| header.resume == key);
+        self.remove_each::<model::HeaderSection>(headers);
+        self.cache_of::<model::SummarySection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::SkillsSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::EducationSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::ExperienceSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::ProjectSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::CertificationSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::AwardSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::PublicationSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::VolunteerSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::LanguageSection>()
+            .forget_where(|section| section.resume == key);
+        self.cache_of::<model::SelectedBullet>()
+            .forget_where(|bullet| bullet.resume == key);
+        key
+    }
+}
+
+impl Remove<model::HeaderSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::HeaderSection>().forget(&key);
+        self.cache_of::<model::Contact>()
+            .forget_where(|contact| contact.header == key);
+        key
+    }
+}
+
+impl Remove<model::Contact, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Contact>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::Skill, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Skill>().forget(&key);
+        self.cache_of::<model::Job>()
+            .modify_all(|job| unlist(&mut job.skills, key));
+        self.cache_of::<model::Project>()
+            .modify_all(|project| unlist(&mut project.skills, key));
+        self.cache_of::<model::Degree>()
+            .modify_all(|degree| unlist(&mut degree.skills, key));
+        self.cache_of::<model::Course>()
+            .modify_all(|course| unlist(&mut course.skills, key));
+        self.cache_of::<model::SkillsSection>()
+            .modify_all(|section| unlist(&mut section.skills, key));
+        key
+    }
+}
+
+impl Remove<model::SkillCategory, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::SkillCategory>().forget(&key);
+        // skills are left uncategorized, but nested categories go too
+        self.cache_of::<model::Skill>().modify_all(|skill| {
+            if skill.category == Some(key) {
+                skill.category = None;
+            }
+        });
+        let children = self
+            .cache_of::<model::SkillCategory>()
+            .forget_where(|category| category.parent == Some(key));
+        self.remove_each::<model::SkillCategory>(children);
+        key
+    }
+}
+
+impl Remove<model::Job, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Job>().forget(&key);
+        let bullets = self
+            .cache_of::<model::DescriptionItem>()
+            .forget_where(|item| item.parent == model::Parent::Job(key));
+        self.remove_each::<model::DescriptionItem>(bullets);
+        self.cache_of::<model::ExperienceSection>()
+            .modify_all(|section| unlist(&mut section.jobs, key));
+        key
+    }
+}
+
+impl Remove<model::Project, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Project>().forget(&key);
+        let bullets = self
+            .cache_of::<model::DescriptionItem>()
+            .forget_where(|item| item.parent == model::Parent::Project(key));
+        self.remove_each::<model::DescriptionItem>(bullets);
+        self.cache_of::<model::ProjectSection>()
+            .modify_all(|section| unlist(&mut section.projects, key));
+        key
+    }
+}
+
+impl Remove<model::DescriptionItem, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::DescriptionItem>().forget(&key);
+        self.cache_of::<model::SelectedBullet>()
+            .forget_where(|bullet| bullet.item == key);
+        key
+    }
+}
+
+impl Remove<model::Degree, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Degree>().forget(&key);
+        self.cache_of::<model::Course>()
+            .forget_where(|course| course.degree == key);
+        self.cache_of::<model::EducationSection>()
+            .modify_all(|section| unlist(&mut section.degrees, key));
+        key
+    }
+}
+
+impl Remove<model::Course, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Course>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::Certification, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Certification>().forget(&key);
+        self.cache_of::<model::CertificationSection>()
+            .modify_all(|section| unlist(&mut section.certifications, key));
+        key
+    }
+}
+
+impl Remove<model::Award, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Award>().forget(&key);
+        self.cache_of::<model::AwardSection>()
+            .modify_all(|section| unlist(&mut section.awards, key));
+        key
+    }
+}
+
+impl Remove<model::Publication, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Publication>().forget(&key);
+        self.cache_of::<model::PublicationSection>()
+            .modify_all(|section| unlist(&mut section.publications, key));
+        key
+    }
+}
+
+impl Remove<model::Volunteer, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Volunteer>().forget(&key);
+        self.cache_of::<model::VolunteerSection>()
+            .modify_all(|section| unlist(&mut section.volunteering, key));
+        key
+    }
+}
+
+impl Remove<model::Language, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::Language>().forget(&key);
+        self.cache_of::<model::LanguageSection>()
+            .modify_all(|section| unlist(&mut section.languages, key));
+        key
+    }
+}
+
+impl Remove<model::SummarySection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::SummarySection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::SkillsSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::SkillsSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::EducationSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::EducationSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::ExperienceSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::ExperienceSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::ProjectSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::ProjectSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::CertificationSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::CertificationSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::AwardSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::AwardSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::PublicationSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::PublicationSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::VolunteerSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::VolunteerSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::LanguageSection, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::LanguageSection>().forget(&key);
+        key
+    }
+}
+
+impl Remove<model::SelectedBullet, Key> for AppState {
+    fn remove(&mut self, key: Key) -> Key {
+        self.cache_of::<model::SelectedBullet>().forget(&key);
+        key
+    }
+}
+
+/// Where values saved to the db ended up, so any keys referring to them can be rewritten.
+///
+/// Temp keys are only unique amongst values of the same type, so each is recorded along with the
+/// type of value it refers to.
+#[derive(Debug, Default)]
+pub struct KeyMap(HashMap<(TypeId, Key), Key>);
+
+impl KeyMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that the `V` under the `from` key is now found under the `to` key instead.
+    pub fn insert<V: 'static>(&mut self, from: Key, to: Key) {
+        self.0.insert((TypeId::of::<V>(), from), to);
+    }
+
+    /// Get the key the `V` under the given key is found under now, which is the same key if it
+    /// hasn't moved.
+    pub fn get<V: 'static>(&self, key: Key) -> Key {
+        self.0
+            .get(&(TypeId::of::<V>(), key))
+            .copied()
+            .unwrap_or(key)
+    }
+
+    /// Rewrite a key referring to a `V` to wherever that `V` is found now.
+    pub fn rekey<V: 'static>(&self, key: &mut Key) {
+        *key = self.get::<V>(*key);
+    }
+
+    /// Rewrite every key in the given list, each referring to a `V`.
+    pub fn rekey_all<V: 'static>(&self, keys: &mut [Key]) {
+        for key in keys {
+            self.rekey::<V>(key);
+        }
+    }
+}
+
+/// Rewrite any keys a value holds to wherever the values they refer to are found now.
+pub trait Rekey {
+    fn rekey(&mut self, keys: &KeyMap);
+}
+
+impl AppState {
+    /// Rewrite every key in every cache, & every key held by a cached value.
+    pub fn rekey(&mut self, keys: &KeyMap) {
+        (&mut self.caches).map(RekeyCache(keys));
+    }
+}
+
+impl Rekey for model::Resume {
+    fn rekey(&mut self, _keys: &KeyMap) {}
+}
+
+impl Rekey for model::Certification {
+    fn rekey(&mut self, _keys: &KeyMap) {}
+}
+
+impl Rekey for model::Award {
+    fn rekey(&mut self, _keys: &KeyMap) {}
+}
+
+impl Rekey for model::Publication {
+    fn rekey(&mut self, _keys: &KeyMap) {}
+}
+
+impl Rekey for model::Volunteer {
+    fn rekey(&mut self, _keys: &KeyMap) {}
+}
+
+impl Rekey for model::Language {
+    fn rekey(&mut self, _keys: &KeyMap) {}
+}
+
+impl Rekey for model::Contact {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::HeaderSection>(&mut self.header);
+    }
+}
+
+impl Rekey for model::Skill {
+    fn rekey(&mut self, keys: &KeyMap) {
+        if let Some(category) = &mut self.category {
+            keys.rekey::<model::SkillCategory>(category);
+        }
+    }
+}
+
+impl Rekey for model::SkillCategory {
+    fn rekey(&mut self, keys: &KeyMap) {
+        if let Some(parent) = &mut self.parent {
+            keys.rekey::<model::SkillCategory>(parent);
+        }
+    }
+}
+
+impl Rekey for model::Job {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey_all::<model::Skill>(&mut self.skills);
+    }
+}
+
+impl Rekey for model::DescriptionItem {
+    fn rekey(&mut self, keys: &KeyMap) {
+        match &mut self.parent {
+            model::Parent::Job(job) => keys.rekey::<model::Job>(job),
+            model::Parent::Project(project) => keys.rekey::<model::Project>(project),
+        }
+    }
+}
+
+impl Rekey for model::Degree {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey_all::<model::Skill>(&mut self.skills);
+    }
+}
+
+impl Rekey for model::Course {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Degree>(&mut self.degree);
+        keys.rekey_all::<model::Skill>(&mut self.skills);
+    }
+}
+
+impl Rekey for model::Project {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey_all::<model::Skill>(&mut self.skills);
+    }
+}
+
+impl Rekey for model::HeaderSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+    }
+}
+
+impl Rekey for model::SummarySection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+    }
+}
+
+impl Rekey for model::SkillsSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Skill>(&mut self.skills);
+    }
+}
+
+impl Rekey for model::EducationSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Degree>(&mut self.degrees);
+    }
+}
+
+impl Rekey for model::ExperienceSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Job>(&mut self.jobs);
+    }
+}
+
+impl Rekey for model::ProjectSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Project>(&mut self.projects);
+    }
+}
+
+impl Rekey for model::CertificationSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Certification>(&mut self.certifications);
+    }
+}
+
+impl Rekey for model::AwardSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Award>(&mut self.awards);
+    }
+}
+
+impl Rekey for model::PublicationSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Publication>(&mut self.publications);
+    }
+}
+
+impl Rekey for model::VolunteerSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Volunteer>(&mut self.volunteering);
+    }
+}
+
+impl Rekey for model::LanguageSection {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey_all::<model::Language>(&mut self.languages);
+    }
+}
+
+impl Rekey for model::SelectedBullet {
+    fn rekey(&mut self, keys: &KeyMap) {
+        keys.rekey::<model::Resume>(&mut self.resume);
+        keys.rekey::<model::DescriptionItem>(&mut self.item);
     }
 }
 
 /// A db cache separating values found from the db (cache) from values that have yet to be saved
 /// to the db (temp). Tracks temporary key values in memory, providing value for the next item.
 #[derive(Debug, Clone)]
-struct TempCache<V> {
+pub struct TempCache<V> {
     next_tmp_key: i64,
     cache: HashMap<Key, V>,
+    /// keys of values removed, so they aren't loaded again from the db before it's saved
+    removed: HashSet<Key>,
 }
 
 impl<V> TempCache<V> {
@@ -91,15 +805,102 @@ impl<V> TempCache<V> {
         Self {
             next_tmp_key: Default::default(),
             cache: HashMap::new(),
+            removed: HashSet::new(),
+        }
+    }
+
+    /// Move any values found under a new key to that key, rewriting the keys they hold too.
+    fn rekey(&mut self, keys: &KeyMap)
+    where
+        V: Rekey + 'static,
+    {
+        self.cache = self
+            .cache
+            .drain()
+            .map(|(key, mut value)| {
+                value.rekey(keys);
+                (keys.get::<V>(key), value)
+            })
+            .collect();
+        self.removed = self.removed.drain().map(|key| keys.get::<V>(key)).collect();
+    }
+
+    /// Use up the next temp key without adding anything under it, so values added after get the
+    /// keys they would've if something had been.
+    fn reserve(&mut self) -> Key {
+        let key = Key::Tmp(self.next_tmp_key);
+        self.next_tmp_key += 1;
+        key
+    }
+
+    /// Take back the most recently added value, so the next one added gets it's temp key again.
+    fn unadd(&mut self) -> Key {
+        self.next_tmp_key -= 1;
+        let key = Key::Tmp(self.next_tmp_key);
+        self.cache.remove(&key);
+        key
+    }
+
+    /// Forget the value held under a key, if there is one, & remember it's been removed.
+    fn forget(&mut self, key: &Key) {
+        self.cache.remove(key);
+        self.removed.insert(*key);
+    }
+
+    /// Forget every value matching the predicate, returning the keys they were held under.
+    fn forget_where(&mut self, matches: impl Fn(&V) -> bool) -> Vec<Key> {
+        let keys: Vec<Key> = self
+            .cache
+            .iter()
+            .filter(|(_, value)| matches(value))
+            .map(|(key, _)| *key)
+            .collect();
+        for key in &keys {
+            self.forget(key);
+        }
+        keys
+    }
+
+    /// Modify every value held, e.g. to drop references to a value that's been removed.
+    fn modify_all(&mut self, modify: impl FnMut(&mut V)) {
+        self.cache.values_mut().for_each(modify);
+    }
+
+    /// Every value held that differs from an earlier version of the cache.
+    fn diff(&self, earlier: &Self, diff: &mut Vec<(&'static str, Key, Status)>)
+    where
+        V: model::DbModel + PartialEq,
+    {
+        let (before, after) = (&earlier.cache, &self.cache);
+        let mut keys: Vec<&Key> = before.keys().chain(after.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            let status = match (before.get(key), after.get(key)) {
+                (None, Some(_)) if matches!(key, Key::Tmp(_)) => Status::Added,
+                // values saved already can only have been loaded after the earlier state
+                (None, Some(_)) => Status::Modified,
+                (Some(_), None) => Status::Deleted,
+                (Some(old), Some(new)) if old != new => Status::Modified,
+                _ => continue,
+            };
+            diff.push((V::TABLE, *key, status));
         }
     }
 
     /// A temp cache knows it's length.
+    #[allow(dead_code)]
     fn len(&self) -> usize {
         self.cache.len()
     }
 }
 
+impl<V> Default for TempCache<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn new_temp_cache_starts_empty() {
@@ -114,7 +915,8 @@ impl<V> AddNew<V, Key> for TempCache<V> {
     fn add_new(&mut self, value: V) -> Key {
         let key = Key::Tmp(self.next_tmp_key);
         self.cache.insert(key, value);
-        self.next_tmp_key;
+        self.removed.remove(&key);
+        self.next_tmp_key += 1;
         key
     }
 }
@@ -128,28 +930,185 @@ fn temp_cache_can_add_new_new_values() {
     assert_eq!(t.len(), 1, "{t:?} should have exactly one item in it.");
 }
 
+impl<V> Replace<V, Key> for TempCache<V> {
+    /// Overwrite the value under the given key, whether it's a temp key or a db key.
+    fn replace(&mut self, key: Key, value: V) -> Key {
+        self.cache.insert(key, value);
+        self.removed.remove(&key);
+        key
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn temp_cache_can_unadd_the_last_value() {
+    let mut t = TempCache::<String>::new();
+    t.add_new("first".into());
+    let key = t.add_new("second".into());
+
+    assert_eq!(t.unadd(), key);
+    assert_eq!(t.len(), 1, "{t:?} should only have the first item left.");
+    assert_eq!(
+        t.add_new("again".into()),
+        key,
+        "the freed up key should be reused"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn temp_cache_can_replace_values() {
+    let mut t = TempCache::<String>::new();
+    let key = t.add_new("original".into());
+    t.replace(key, "replaced".into());
+
+    assert_eq!(t.cache.get(&key), Some(&"replaced".into()));
+    assert_eq!(
+        t.len(),
+        1,
+        "{t:?} should still have exactly one item in it."
+    );
+}
+
 #[async_trait]
-impl<V: Sync> Get<V, Key> for TempCache<V> {
+impl<V> Get<V, Key> for TempCache<V>
+where
+    V: model::DbModel + Send + Sync,
+{
     /// Extract a value with a matching id from the cache, or the underlying data store if not
-    /// found in cache (updating the cache when found), or None if no matching value is found.
-    async fn get(&self, key: &Key) -> Option<&V> {
-        // first we check if the requested id is in the cache
-        if let Some(value) = self.cache.get(key) {
-            return Some(value);
-        } else {
-            // TODO: actually get this from the db instead
-            None
+    /// found in cache (updating the cache when found), or None if no matching value is found or
+    /// it's been removed.
+    async fn get(&mut self, conn: &mut SqliteConnection, key: &Key) -> anyhow::Result<Option<&V>> {
+        // first we check if the requested id is in the cache, or was removed from it
+        if !self.cache.contains_key(key) && !self.removed.contains(key) {
+            // if not, only values that have been saved can be found in the db
+            let Key::Db(id) = key else { return Ok(None) };
+            match V::get_one_by_id(conn, *id).await {
+                Ok(value) => {
+                    self.cache.insert(*key, value);
+                }
+                Err(e) if matches!(e.downcast_ref(), Some(sqlx::Error::RowNotFound)) => {
+                    return Ok(None)
+                }
+                Err(e) => return Err(e),
+            }
         }
-        // finally, if not found in either, we check the db & load it into the cache if found
+
+        Ok(self.cache.get(key))
     }
 }
 
 #[cfg(test)]
 #[tokio::test]
 async fn can_get_items_from_temp_cache() {
-    let mut t = TempCache::<String>::new();
-    let id = t.add_new("This is a new value".into());
-    let gotten = t.get(&id).await;
+    let mut conn = model::test_pool().await.acquire().await.unwrap();
+    let mut t = TempCache::<model::Resume>::new();
+    let id = t.add_new(model::Resume::new("This is a new value".into()));
+    let gotten = t.get(&mut conn, &id).await.unwrap();
+
+    assert_eq!(
+        gotten,
+        Some(&model::Resume::new("This is a new value".into()))
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn temp_cache_loads_missing_items_from_db() {
+    use model::DbModel;
+
+    let mut conn = model::test_pool().await.acquire().await.unwrap();
+    let id = model::Resume::new("Saved".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    let mut t = TempCache::<model::Resume>::new();
+
+    let gotten = t.get(&mut conn, &Key::Db(id)).await.unwrap().cloned();
+    assert_eq!(gotten, Some(model::Resume::new("Saved".into())));
+    assert_eq!(t.len(), 1, "{t:?} should keep the value it loaded.");
+    assert_eq!(t.get(&mut conn, &Key::Db(id + 1)).await.unwrap(), None);
+    assert_eq!(t.get(&mut conn, &Key::Tmp(0)).await.unwrap(), None);
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn removed_values_arent_loaded_again() {
+    use model::DbModel;
+
+    let mut conn = model::test_pool().await.acquire().await.unwrap();
+    let id = model::Resume::new("Saved".into())
+        .create(&mut conn)
+        .await
+        .unwrap();
+    let mut state = AppState::new();
+    let _: Option<&model::Resume> = state.get(&mut conn, &Key::Db(id)).await.unwrap();
+
+    Remove::<model::Resume, Key>::remove(&mut state, Key::Db(id));
+    let gotten: Option<&model::Resume> = state.get(&mut conn, &Key::Db(id)).await.unwrap();
+    assert_eq!(
+        gotten, None,
+        "the removed resume is still in the db, but shouldn't be loaded"
+    );
+
+    state.replace(Key::Db(id), model::Resume::new("Replaced".into()));
+    let gotten: Option<&model::Resume> = state.get(&mut conn, &Key::Db(id)).await.unwrap();
+    assert_eq!(gotten, Some(&model::Resume::new("Replaced".into())));
+}
+
+#[cfg(test)]
+#[test]
+fn registered_types_each_get_their_own_cache() {
+    let mut state = AppState::new();
+    let resume = state.add_new(model::Resume::new("Backend".into()));
+    let award = state.add_new(model::Award::new("Best".into()));
+
+    state.replace(resume, model::Resume::new("Frontend".into()));
+
+    // keys only need to be unique within a type
+    assert_eq!(resume, award);
+    assert_eq!(
+        state.cached::<model::Resume>(&resume),
+        Some(&model::Resume::new("Frontend".into()))
+    );
+    assert_eq!(
+        state.diff(&AppState::new()),
+        vec![
+            ("Resume", resume, Status::Added),
+            ("Award", award, Status::Added)
+        ]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn removing_a_job_removes_what_depends_on_it() {
+    use model::ListedSection;
+
+    let mut state = AppState::new();
+    let start = "2020-01-01".parse().unwrap();
+    let job = state.add_new(model::Job::new(
+        "Acme".into(),
+        "Eng".into(),
+        start,
+        None,
+        vec![],
+    ));
+    let bullet = state.add_new(model::DescriptionItem::new(
+        model::Parent::Job(job),
+        0,
+        "Did things".into(),
+    ));
+    let resume = state.add_new(model::Resume::new("Backend".into()));
+    let selected = state.add_new(model::SelectedBullet::new(resume, bullet, 0));
+    let section = state.add_new(model::ExperienceSection::with_items(resume, vec![job]));
+
+    Remove::<model::Job, Key>::remove(&mut state, job);
 
-    assert_eq!(gotten, Some(&"This is a new value".into()));
+    assert_eq!(state.cached::<model::Job>(&job), None);
+    assert_eq!(state.cached::<model::DescriptionItem>(&bullet), None);
+    assert_eq!(state.cached::<model::SelectedBullet>(&selected), None);
+    let section: Option<&model::ExperienceSection> = state.cached(&section);
+    assert_eq!(section.map(|s| s.jobs.len()), Some(0));
+    assert!(state.cached::<model::Resume>(&resume).is_some());
 }
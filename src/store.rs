@@ -2,63 +2,1389 @@
 //
 // TODO:
 // - [x] impl Get from Store deferred down to Tempcaches on State
-// - [ ] impl a save method on Store
-// - [ ] impl db logic on Get
-// - [ ] some sort of Update or Modify trait
+// - [x] impl a save method on Store
+// - [x] impl db logic on Get
+// - [x] some sort of Update or Modify trait
 // - [x] track Changes in vec on Store
-// - [ ] undo/redo Changes
+// - [x] undo/redo Changes
 // - [ ] TESTS!
-use sqlx::SqlitePool;
+use std::{
+    any::Any,
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use log::warn;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use sqlx::{SqliteConnection, SqlitePool};
 
 use crate::{
-    changelist::{Apply, ChangeList},
-    model,
-    state::{AddNew, AppState, Key},
+    changelist::{Absorbed, Apply, ApplyTo, ChangeList, Compact, IntoBoxed},
+    history::{self, Batch, Logged, Snapshot},
+    model::{self, DbModel},
+    state::{AddNew, AppState, Caches, Get, Key, KeyMap, Rekey, Remove, Replace, Status},
 };
 
 /// A data store, containing application state & handling db updates.
 #[derive(Debug)]
 pub struct Store {
     pool: SqlitePool,
-    // TODO: track two state objects: initial state (from db) & current state initial modified by
-    // changes--allowing us to only apply changes from initial state to db on save
-    // this maybe leads to also maintaining a vec of changes, where if one were to apply
-    // each change in order to the initial state, they would arrive at the current state--this
-    // might give "free" info functionality if desired
+    // initial state is the state as of the last save, current is initial modified by changes--
+    // allowing us to only apply changes from initial state to db on save
     initial: AppState,
     current: AppState,
     // for now, assume that current is always up to date from all changes
     // enforce this by: always applying a change when pushing it to changes
     //                  and always applying the inverse change when popping it to undone
-    changes: ChangeList<AppState, Key>,
-    undone: ChangeList<AppState, Key>,
+    changes: ChangeList<AppState, Key, dyn StoreChange>,
+    undone: ChangeList<AppState, Key, dyn StoreChange>,
+    // identifies this run of the app in the journal, grouping the changes made during it
+    session: String,
+    // the db's generation as of when state was read from it, if known, to tell on save whether
+    // anyone else has written to it since
+    generation: Option<i64>,
+    // on save, apply changes over anyone else's saved since, instead of failing on conflicts
+    rebase: bool,
 }
 
 impl Store {
     pub fn new(pool: SqlitePool) -> Self {
+        let session = format!(
+            "{}-{}",
+            Utc::now().format("%Y%m%d%H%M%S"),
+            std::process::id()
+        );
+
         Self {
             pool,
+            session,
             initial: AppState::new(),
             current: AppState::new(),
             changes: ChangeList::new(),
             undone: ChangeList::new(),
+            generation: None,
+            rebase: false,
+        }
+    }
+
+    /// Create a store for the db as it is now, so saves can tell if anyone else has written to it
+    /// since.
+    pub async fn open(pool: SqlitePool) -> anyhow::Result<Self> {
+        let mut conn = pool.acquire().await?;
+        let generation = history::generation(&mut conn).await?;
+        drop(conn);
+
+        let mut store = Self::new(pool);
+        store.generation = Some(generation);
+        Ok(store)
+    }
+
+    /// Whether saves should rebase changes onto anything else saved since the db was read,
+    /// rather than fail when they conflict with it, & whether undoing & redoing saves should
+    /// overwrite anything changed since, rather than fail.
+    pub fn rebase(mut self, rebase: bool) -> Self {
+        self.rebase = rebase;
+        self
+    }
+
+    /// Borrow the pool for reading saved data directly from the db.
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// Get a value as of the latest change to it, loading it from the db if it's been saved but
+    /// hasn't been needed yet.
+    pub async fn get<V>(&mut self, key: &Key) -> anyhow::Result<Option<&V>>
+    where
+        AppState: Get<V, Key>,
+        V: 'static,
+    {
+        let mut conn = self.pool.acquire().await?;
+        // keep saved values as they were loaded too, so changes to them can be told apart from
+        // loading them
+        Get::<V, Key>::get(&mut self.initial, &mut conn, key).await?;
+        self.current.get(&mut conn, key).await
+    }
+
+    /// Every value changed since the last save, as far as state knows, by type.
+    pub fn status(&self) -> Vec<(&'static str, Key, Status)> {
+        self.current.diff(&self.initial)
+    }
+
+    /// Write every change made since the last save to the db in a single transaction, then
+    /// rewrite any temp keys in state to the db keys their values were saved under.
+    ///
+    /// If any change fails to save, none of them are & the store is left as it was.
+    ///
+    /// If anyone else has saved to the db since it was read, any change to a value they changed
+    /// too is a conflict, failing the save with a [`Conflicts`] error listing them, unless the
+    /// store rebases, in which case edits only overwrite the fields they changed themselves.
+    pub async fn save(&mut self) -> anyhow::Result<KeyMap> {
+        // an empty save would only clear what's left to redo
+        if self.changes.iter().next().is_none() {
+            return Ok(KeyMap::new());
+        }
+        // fold changes together first, so the journal gets each value's end result
+        self.changes.compact(&self.initial);
+        // replaying the changes from the initial state gives the key each one was applied under
+        let mut replayed = self.initial.clone();
+        let applied = self.changes.apply_all(&mut replayed);
+
+        // dropping the transaction without committing it rolls it back
+        let mut tx = self.pool.begin().await?;
+        let generation = history::bump_generation(&mut tx).await?;
+        let stale = self.generation.is_some_and(|read| read != generation);
+        let mut saving = Saving {
+            keys: KeyMap::new(),
+            batch: Batch::start(&mut tx, &self.session).await?,
+            base: stale.then_some(&self.initial),
+            rebase: self.rebase,
+            conflicts: Vec::new(),
+        };
+        for (change, key) in self.changes.iter().zip(applied) {
+            change.persist(&mut tx, key, &mut saving).await?;
+        }
+        if !saving.conflicts.is_empty() {
+            return Err(Conflicts(saving.conflicts).into());
+        }
+        tx.commit().await?;
+        let keys = saving.keys;
+        self.generation = Some(generation + 1);
+
+        self.current.rekey(&keys);
+        self.initial = self.current.clone();
+        self.changes = ChangeList::new();
+        self.undone = ChangeList::new();
+
+        Ok(keys)
+    }
+
+    /// Undo the most recent change, returning it, or `None` if there's nothing left to undo.
+    ///
+    /// Changes made since the last save are undone one at a time. Once there are none left, each
+    /// undo reverts the most recent save still in the db's history instead, however many changes
+    /// it wrote, failing if any row it wrote has changed since, unless rebasing.
+    pub async fn undo(&mut self) -> anyhow::Result<Option<Step>> {
+        if self
+            .changes
+            .undo_into(&mut self.undone, &self.initial, &mut self.current)
+            .is_some()
+        {
+            return Ok(Some(Step::Unsaved));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let generation = history::bump_generation(&mut tx).await?;
+        let undone = history::undo(&mut tx, &self.session, self.rebase).await?;
+        tx.commit().await?;
+        self.forget();
+        self.generation = Some(generation + 1);
+
+        Ok(undone.map(Step::Saved))
+    }
+
+    /// Redo the most recently undone change, returning it, or `None` if there's nothing left to
+    /// redo.
+    pub async fn redo(&mut self) -> anyhow::Result<Option<Step>> {
+        if self
+            .changes
+            .redo_from(&mut self.undone, &mut self.current)
+            .is_some()
+        {
+            return Ok(Some(Step::Unsaved));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let generation = history::bump_generation(&mut tx).await?;
+        let redone = history::redo(&mut tx, &self.session, self.rebase).await?;
+        tx.commit().await?;
+        self.forget();
+        self.generation = Some(generation + 1);
+
+        Ok(redone.map(Step::Saved))
+    }
+
+    /// Apply a new change & add it to the list, leaving nothing undone to redo.
+    fn push<C>(&mut self, change: C) -> Key
+    where
+        C: StoreChange + 'static,
+    {
+        let key = change.apply_to(&mut self.current);
+        self.changes.push(change);
+        self.undone = ChangeList::new();
+
+        key
+    }
+
+    /// Drop everything held in state, e.g. after the db was changed out from under it.
+    fn forget(&mut self) {
+        self.initial = AppState::new();
+        self.current = AppState::new();
+        self.undone = ChangeList::new();
+    }
+}
+
+/// A change undone or redone.
+#[derive(Debug, PartialEq)]
+pub enum Step {
+    /// a single change made since the last save
+    Unsaved,
+    /// everything a save wrote to the db
+    Saved(history::Save),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Unsaved => write!(f, "an unsaved change"),
+            Step::Saved(save) => write!(f, "{save}"),
+        }
+    }
+}
+
+/// A change the store can both apply to it's state & write to the db.
+pub trait StoreChange: ApplyTo<AppState, Key> + Persist + Fold {}
+
+impl<C> StoreChange for C where C: ApplyTo<AppState, Key> + Persist + Fold {}
+
+impl Compact<Key> for dyn StoreChange {
+    fn absorb(&self, key: &Key, later: &Self) -> Absorbed<Self> {
+        later.fold_into(self, key)
+    }
+}
+
+/// Fold a change into earlier ones, so saves write as few changes as they can.
+///
+/// Edits fold into the add or edit of the value they edit, so long as only edits to other values
+/// come between them, & deletes cancel out the add or edit just before them.
+pub trait Fold: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    /// Fold this change into an earlier one, given the key the earlier one was applied under.
+    fn fold_into(&self, earlier: &dyn StoreChange, key: &Key) -> Absorbed<dyn StoreChange>;
+
+    /// Whether later changes to other values can be moved back before this one.
+    fn passable(&self) -> bool {
+        false
+    }
+}
+
+/// Nothing comes before an add for it to fold into, as it's values can refer to anything added
+/// before it.
+impl<M> Fold for M
+where
+    M: DbModel + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn fold_into(&self, _earlier: &dyn StoreChange, _key: &Key) -> Absorbed<dyn StoreChange> {
+        Absorbed::Blocked
+    }
+}
+
+/// An edit after adding or editing the same value replaces the value added or edited. Edits can't
+/// move back past adds, as they may refer to the value added.
+impl<M> Fold for (Key, M)
+where
+    M: DbModel + Rekey + Serialize + DeserializeOwned + Clone + PartialEq + Debug + Send + Sync,
+    M: 'static,
+    AppState: Replace<M, Key> + AddNew<M, Key> + Caches<M>,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn fold_into(&self, earlier: &dyn StoreChange, key: &Key) -> Absorbed<dyn StoreChange> {
+        let (replaced, value) = self;
+        if earlier.as_any().downcast_ref::<M>().is_some() && key == replaced {
+            return Absorbed::Into(Box::new(value.clone()));
+        }
+        match earlier.as_any().downcast_ref::<(Key, M)>() {
+            Some((edited, _)) if edited == replaced => Absorbed::Into(Box::new(self.clone())),
+            _ if earlier.passable() => Absorbed::Passed,
+            _ => Absorbed::Blocked,
+        }
+    }
+
+    fn passable(&self) -> bool {
+        true
+    }
+}
+
+/// A delete just after adding a value cancels the add, & just after editing it makes the edit
+/// pointless.
+impl<M> Fold for Delete<M>
+where
+    M: DbModel + PartialEq + Debug + Send + Sync + 'static,
+    AppState: Remove<M, Key> + Caches<M>,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn fold_into(&self, earlier: &dyn StoreChange, key: &Key) -> Absorbed<dyn StoreChange> {
+        let earlier = earlier.as_any();
+        if earlier.downcast_ref::<M>().is_some() && *key == self.key {
+            return Absorbed::Into(Box::new(Reserve::<M>::new()));
+        }
+        match earlier.downcast_ref::<(Key, M)>() {
+            Some((edited, _)) if *edited == self.key => {
+                Absorbed::Into(Box::new(Delete::<M>::new(self.key)))
+            }
+            _ => Absorbed::Blocked,
+        }
+    }
+}
+
+impl<M> Fold for Reserve<M>
+where
+    M: Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn fold_into(&self, _earlier: &dyn StoreChange, _key: &Key) -> Absorbed<dyn StoreChange> {
+        Absorbed::Blocked
+    }
+
+    fn passable(&self) -> bool {
+        true
+    }
+}
+
+impl<C> IntoBoxed<dyn StoreChange> for C
+where
+    C: StoreChange + 'static,
+{
+    fn into_boxed(self) -> Box<dyn StoreChange> {
+        Box::new(self)
+    }
+}
+
+/// Write a change to the db.
+#[async_trait]
+pub trait Persist: Send + Sync {
+    /// Write this change & record it in the save's batch, given the key applying it to state gave,
+    /// recording where any value it adds ends up.
+    async fn persist(
+        &self,
+        conn: &mut SqliteConnection,
+        key: Key,
+        saving: &mut Saving<'_>,
+    ) -> anyhow::Result<()>;
+}
+
+/// A save in progress.
+#[derive(Debug)]
+pub struct Saving<'a> {
+    /// where the values saved so far ended up
+    keys: KeyMap,
+    batch: Batch,
+    /// the state changes were made from, only given if anyone else has saved to the db since it
+    /// was read, to check values being saved over are still the ones changes were made from
+    base: Option<&'a AppState>,
+    /// merge changes into anyone else's instead of counting them as conflicts
+    rebase: bool,
+    conflicts: Vec<Conflict>,
+}
+
+impl Saving<'_> {
+    /// Whether a saved value is still the one a change to it was made from, `None` if it's since
+    /// been deleted. Any other value is a conflict, unless rebasing.
+    fn unchanged<M>(&mut self, key: Key, id: i64, saved: Option<&M>) -> bool
+    where
+        M: DbModel + PartialEq,
+        AppState: Caches<M>,
+    {
+        let Some(base) = self.base else {
+            return true;
+        };
+        // values that weren't read before being changed can't have been read out of date
+        if base
+            .cached::<M>(&key)
+            .is_none_or(|base| Some(base) == saved)
+        {
+            return true;
+        }
+        if !self.rebase {
+            self.conflicts.push(Conflict {
+                model: M::TABLE,
+                id,
+                deleted: saved.is_none(),
+            });
+        }
+        false
+    }
+}
+
+/// A saved value someone else changed after it was read, that a change would overwrite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub model: &'static str,
+    pub id: i64,
+    /// whether it was deleted rather than edited
+    pub deleted: bool,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let how = if self.deleted { "deleted" } else { "edited" };
+        write!(f, "{} {} was {how}", self.model, self.id)
+    }
+}
+
+/// Changes that couldn't be saved, as someone else saved changes to the same values after they
+/// were read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflicts(pub Vec<Conflict>);
+
+impl fmt::Display for Conflicts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Changes conflict with ones saved since they were made:")?;
+        for conflict in &self.0 {
+            writeln!(f, "  {conflict}")?;
         }
+        write!(f, "Run again with --rebase to apply them on top")
     }
 }
 
-// TODO: Test this!
-/// Add resumes to store, deferring to state.
-impl AddNew<model::Resume, Key> for Store {
-    fn add_new(&mut self, value: model::Resume) -> Key {
-        let new_key = self.current.apply(&value);
-        self.changes.push(value);
+impl std::error::Error for Conflicts {}
 
-        new_key
+/// Adding a value inserts a new row for it.
+#[async_trait]
+impl<M> Persist for M
+where
+    M: DbModel + Rekey + Serialize + Clone + Send + Sync + 'static,
+{
+    async fn persist(
+        &self,
+        conn: &mut SqliteConnection,
+        key: Key,
+        saving: &mut Saving<'_>,
+    ) -> anyhow::Result<()> {
+        let mut value = self.clone();
+        value.rekey(&saving.keys);
+        let id = value.create(conn).await?;
+        saving.keys.insert::<M>(key, Key::Db(id));
+
+        let logged = Logged::Add {
+            model: M::TABLE.into(),
+            id,
+            value: serde_json::to_value(&value)?,
+        };
+        saving.batch.log(conn, &logged).await?;
+        saving.batch.record(conn, id, None, Some(&value)).await
     }
 }
 
-impl Apply<model::Resume, Key> for AppState {
-    fn apply(&mut self, change: &model::Resume) -> Key {
+/// Replacing a value overwrites the row it was stored in.
+///
+/// Rebasing onto a row someone else changed only overwrites the fields changed from the value it
+/// was read as, keeping the rest of theirs. Rows they deleted stay deleted.
+#[async_trait]
+impl<M> Persist for (Key, M)
+where
+    M: DbModel + Rekey + Serialize + DeserializeOwned + Clone + PartialEq + Send + Sync + 'static,
+    AppState: Caches<M>,
+{
+    async fn persist(
+        &self,
+        conn: &mut SqliteConnection,
+        _key: Key,
+        saving: &mut Saving<'_>,
+    ) -> anyhow::Result<()> {
+        let (key, value) = self;
+        let mut value = value.clone();
+        value.rekey(&saving.keys);
+        let id = saving.keys.get::<M>(*key).db_id()?;
+        let before = model::get_if_saved::<M>(conn, id).await?;
+        let unchanged = saving.unchanged(*key, id, before.as_ref());
+        let Some(before) = before else {
+            if unchanged {
+                anyhow::bail!("No {} with id {id}", M::TABLE);
+            }
+            warn!(
+                "Not saving edit to {} {id}, it's since been deleted.",
+                M::TABLE
+            );
+            return Ok(());
+        };
+        if !unchanged {
+            if !saving.rebase {
+                return Ok(());
+            }
+            let base = saving.base.and_then(|base| base.cached::<M>(key));
+            value = merge(base, &value, &before)?;
+        }
+        value.update(conn, id).await?;
+
+        let logged = Logged::Replace {
+            model: M::TABLE.into(),
+            id,
+            value: serde_json::to_value(&value)?,
+        };
+        saving.batch.log(conn, &logged).await?;
+        saving
+            .batch
+            .record(conn, id, Some(&before), Some(&value))
+            .await
+    }
+}
+
+/// Deleting a value deletes it's row, along with every row the db cascades the delete to.
+#[async_trait]
+impl<M> Persist for Delete<M>
+where
+    M: DbModel + PartialEq + Send + Sync + 'static,
+    AppState: Caches<M>,
+{
+    async fn persist(
+        &self,
+        conn: &mut SqliteConnection,
+        _key: Key,
+        saving: &mut Saving<'_>,
+    ) -> anyhow::Result<()> {
+        let id = saving.keys.get::<M>(self.key).db_id()?;
+        if saving.base.is_some() {
+            // deleting something already deleted doesn't conflict with anything
+            let saved = model::get_if_saved::<M>(conn, id).await?;
+            if saved.is_some() && !saving.unchanged(self.key, id, saved.as_ref()) && !saving.rebase
+            {
+                return Ok(());
+            }
+        }
+        // compare the rows the delete can reach to see what it took with it
+        let before = Snapshot::reachable(conn, M::TABLE, id).await?;
+        M::delete(conn, id).await?;
+        let after = before.again(conn).await?;
+
+        let logged = Logged::Delete {
+            model: M::TABLE.into(),
+            id,
+        };
+        saving.batch.log(conn, &logged).await?;
+        saving.batch.record_diff(conn, &before, &after).await
+    }
+}
+
+/// A reserved key was never used, so there's nothing to write.
+#[async_trait]
+impl<M> Persist for Reserve<M>
+where
+    M: Send + Sync + 'static,
+{
+    async fn persist(
+        &self,
+        _conn: &mut SqliteConnection,
+        _key: Key,
+        _saving: &mut Saving<'_>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Apply the fields a value changed from the one it was read as to a newer version of it, keeping
+/// the newer version's other fields. Without knowing what it was read as, the value wins outright.
+fn merge<M>(base: Option<&M>, value: &M, newer: &M) -> anyhow::Result<M>
+where
+    M: Serialize + DeserializeOwned + Clone,
+{
+    let Some(base) = base else {
+        return Ok(value.clone());
+    };
+    let (Value::Object(base), Value::Object(value), Value::Object(mut merged)) = (
+        serde_json::to_value(base)?,
+        serde_json::to_value(value)?,
+        serde_json::to_value(newer)?,
+    ) else {
+        return Ok(value.clone());
+    };
+    for (field, changed) in value {
+        if base.get(&field) != Some(&changed) {
+            merged.insert(field, changed);
+        }
+    }
+
+    Ok(serde_json::from_value(Value::Object(merged))?)
+}
+
+/// Adding a value to state is undone by taking it back out again.
+impl<M> Apply<M, Key> for AppState
+where
+    M: DbModel + Clone + Debug + 'static,
+    AppState: AddNew<M, Key> + Caches<M>,
+{
+    fn apply(&mut self, change: &M) -> Key {
         self.add_new(change.clone())
     }
+
+    fn inverse(&self, change: &M) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(Unadd(change.clone()))
+    }
+}
+
+/// Replacing a value is undone by putting back whatever it replaced.
+impl<M> Apply<(Key, M), Key> for AppState
+where
+    M: DbModel + Clone + Debug + 'static,
+    AppState: Replace<M, Key> + Caches<M>,
+{
+    fn apply(&mut self, (key, value): &(Key, M)) -> Key {
+        self.replace(*key, value.clone())
+    }
+
+    fn inverse(&self, (key, _): &(Key, M)) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(Restore {
+            key: *key,
+            value: self.cached::<M>(key).cloned(),
+        })
+    }
+}
+
+/// Take back the most recently added value, the one given, undoing adding it.
+#[derive(Debug)]
+pub struct Unadd<M>(M);
+
+impl<M> Apply<Unadd<M>, Key> for AppState
+where
+    M: DbModel + Clone + Debug + 'static,
+    AppState: AddNew<M, Key> + Caches<M>,
+{
+    fn apply(&mut self, _change: &Unadd<M>) -> Key {
+        self.unadd::<M>()
+    }
+
+    fn inverse(&self, Unadd(value): &Unadd<M>) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(value.clone())
+    }
+}
+
+/// Put a key back to the value it held, or forget it if it didn't hold one in state.
+#[derive(Debug)]
+pub struct Restore<M> {
+    key: Key,
+    value: Option<M>,
+}
+
+impl<M> Apply<Restore<M>, Key> for AppState
+where
+    M: DbModel + Clone + Debug + 'static,
+    AppState: Caches<M>,
+{
+    fn apply(&mut self, change: &Restore<M>) -> Key {
+        self.restore(change.key, change.value.clone())
+    }
+
+    fn inverse(&self, change: &Restore<M>) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(Restore {
+            key: change.key,
+            value: self.cached::<M>(&change.key).cloned(),
+        })
+    }
+}
+
+/// Delete the value stored under a key, along with everything depending on it.
+#[derive(Debug)]
+pub struct Delete<M> {
+    key: Key,
+    model: PhantomData<fn() -> M>,
+}
+
+impl<M> Delete<M> {
+    pub fn new(key: Key) -> Self {
+        Self {
+            key,
+            model: PhantomData,
+        }
+    }
+}
+
+/// A delete can take any number of other values with it, so it's undone by putting back the whole
+/// state from before it.
+impl<M> Apply<Delete<M>, Key> for AppState
+where
+    M: Debug + 'static,
+    AppState: Remove<M, Key>,
+{
+    fn apply(&mut self, change: &Delete<M>) -> Key {
+        self.remove(change.key)
+    }
+
+    fn inverse(&self, change: &Delete<M>) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(Rewind {
+            key: change.key,
+            state: Box::new(self.clone()),
+        })
+    }
+}
+
+/// Use up a temp key without adding a value under it, left behind when compacting cancels out
+/// adding a value & deleting it, so values added after still get the keys they were added under.
+#[derive(Debug)]
+pub struct Reserve<M>(PhantomData<fn() -> M>);
+
+impl<M> Reserve<M> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M> Apply<Reserve<M>, Key> for AppState
+where
+    M: Debug + 'static,
+    AppState: Caches<M>,
+{
+    fn apply(&mut self, _change: &Reserve<M>) -> Key {
+        self.reserve::<M>()
+    }
+
+    fn inverse(&self, _change: &Reserve<M>) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(Unreserve::<M>(PhantomData))
+    }
+}
+
+/// Give back the most recently reserved temp key, undoing reserving it.
+#[derive(Debug)]
+pub struct Unreserve<M>(PhantomData<fn() -> M>);
+
+impl<M> Apply<Unreserve<M>, Key> for AppState
+where
+    M: Debug + 'static,
+    AppState: Caches<M>,
+{
+    fn apply(&mut self, _change: &Unreserve<M>) -> Key {
+        self.unadd::<M>()
+    }
+
+    fn inverse(&self, _change: &Unreserve<M>) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(Reserve::<M>::new())
+    }
+}
+
+/// Put the whole state back the way it was, e.g. before a delete.
+#[derive(Debug)]
+pub struct Rewind {
+    key: Key,
+    state: Box<AppState>,
+}
+
+impl Apply<Rewind, Key> for AppState {
+    fn apply(&mut self, change: &Rewind) -> Key {
+        *self = (*change.state).clone();
+        change.key
+    }
+
+    fn inverse(&self, change: &Rewind) -> Box<dyn ApplyTo<AppState, Key>> {
+        Box::new(Rewind {
+            key: change.key,
+            state: Box::new(self.clone()),
+        })
+    }
+}
+
+/// Delete any kind of value from store, deferring to state.
+impl<M> Remove<M, Key> for Store
+where
+    M: DbModel + Debug + PartialEq + Send + Sync + 'static,
+    AppState: Remove<M, Key> + Caches<M>,
+{
+    fn remove(&mut self, key: Key) -> Key {
+        self.push(Delete::<M>::new(key))
+    }
+}
+
+/// Add any kind of value to store, deferring to state.
+impl<M> AddNew<M, Key> for Store
+where
+    M: StoreChange + 'static,
+{
+    fn add_new(&mut self, value: M) -> Key {
+        self.push(value)
+    }
+}
+
+/// Replace any kind of value in store with an edited version, deferring to state.
+impl<M> Replace<M, Key> for Store
+where
+    (Key, M): StoreChange + 'static,
+{
+    fn replace(&mut self, key: Key, value: M) -> Key {
+        self.push((key, value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{self, test_pool};
+
+    fn job(title: &str) -> model::Job {
+        model::Job::new(
+            "Acme".into(),
+            title.into(),
+            "2020-01-01".parse().unwrap(),
+            None,
+            Vec::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn save_writes_changes_referring_to_unsaved_values() {
+        let mut store = Store::new(test_pool().await);
+        let job_key = store.add_new(job("Engineer"));
+        let bullet = model::DescriptionItem::new(model::Parent::Job(job_key), 0, "Shipped".into());
+        let bullet_key = store.add_new(bullet);
+        store.replace(job_key, job("Senior Engineer"));
+
+        let keys = store.save().await.unwrap();
+
+        let job_id = keys.get::<model::Job>(job_key).db_id().unwrap();
+        let bullet_id = keys
+            .get::<model::DescriptionItem>(bullet_key)
+            .db_id()
+            .unwrap();
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_all(&mut conn).await.unwrap(),
+            vec![(job_id, job("Senior Engineer"))]
+        );
+        let saved = model::DescriptionItem::get_one_by_id(&mut conn, bullet_id)
+            .await
+            .unwrap();
+        assert_eq!(saved.parent, model::Parent::Job(Key::Db(job_id)));
+        // state now refers to everything by it's db key
+        drop(conn);
+        let cached: Option<&model::DescriptionItem> = store.current.cached(&Key::Db(bullet_id));
+        assert_eq!(cached, Some(&saved));
+        assert_eq!(store.changes.iter().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn failed_save_leaves_db_and_store_untouched() {
+        let mut store = Store::new(test_pool().await);
+        store.add_new(model::Resume::new("Backend".into()));
+        // no resume will ever have this id, so saving the summary fails
+        store.add_new(model::SummarySection::new(Key::Db(99), "Hi".into()));
+
+        assert!(store.save().await.is_err());
+
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert!(model::Resume::get_all(&mut conn).await.unwrap().is_empty());
+        assert_eq!(store.changes.iter().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_loads_saved_values_and_sees_unsaved_edits() {
+        let mut store = Store::new(test_pool().await);
+        let mut conn = store.pool().acquire().await.unwrap();
+        let id = job("Engineer").create(&mut conn).await.unwrap();
+        drop(conn);
+        let key = Key::Db(id);
+
+        let loaded: Option<&model::Job> = store.get(&key).await.unwrap();
+        assert_eq!(loaded, Some(&job("Engineer")));
+        store.replace(key, job("Senior Engineer"));
+        let edited: Option<&model::Job> = store.get(&key).await.unwrap();
+        assert_eq!(edited, Some(&job("Senior Engineer")));
+        let missing: Option<&model::Job> = store.get(&Key::Db(id + 1)).await.unwrap();
+        assert_eq!(missing, None);
+    }
+
+    /// The changes a saved step logged, described as they would be to the user.
+    fn logged(step: Option<Step>) -> Vec<String> {
+        match step {
+            Some(Step::Saved(save)) => save.changes.iter().map(ToString::to_string).collect(),
+            other => panic!("expected a saved step, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn undo_and_redo_unsaved_changes() {
+        let mut store = Store::new(test_pool().await);
+        let key = store.add_new(job("Engineer"));
+        store.replace(key, job("Senior Engineer"));
+
+        assert_eq!(store.undo().await.unwrap(), Some(Step::Unsaved));
+        let cached: Option<&model::Job> = store.get(&key).await.unwrap();
+        assert_eq!(cached, Some(&job("Engineer")));
+        assert_eq!(store.undo().await.unwrap(), Some(Step::Unsaved));
+        let cached: Option<&model::Job> = store.get(&key).await.unwrap();
+        assert_eq!(cached, None);
+
+        assert_eq!(store.redo().await.unwrap(), Some(Step::Unsaved));
+        assert_eq!(store.redo().await.unwrap(), Some(Step::Unsaved));
+        let cached: Option<&model::Job> = store.get(&key).await.unwrap();
+        assert_eq!(cached, Some(&job("Senior Engineer")));
+        assert_eq!(store.redo().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn undo_and_redo_saves() {
+        let mut store = Store::new(test_pool().await);
+        let key = store.add_new(job("Engineer"));
+        let id = store
+            .save()
+            .await
+            .unwrap()
+            .get::<model::Job>(key)
+            .db_id()
+            .unwrap();
+        store.replace(Key::Db(id), job("Senior Engineer"));
+        store.add_new(model::Resume::new("Backend".into()));
+        store.save().await.unwrap();
+
+        // the second save is undone as a whole
+        let edit_and_add = vec![format!("edited Job {id}"), "added Resume 1".to_string()];
+        assert_eq!(logged(store.undo().await.unwrap()), edit_and_add);
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_all(&mut conn).await.unwrap(),
+            vec![(id, job("Engineer"))]
+        );
+        assert!(model::Resume::get_all(&mut conn).await.unwrap().is_empty());
+        drop(conn);
+        let add = vec![format!("added Job {id}")];
+        assert_eq!(logged(store.undo().await.unwrap()), add);
+        assert_eq!(store.undo().await.unwrap(), None);
+
+        assert_eq!(logged(store.redo().await.unwrap()), add);
+        assert_eq!(logged(store.redo().await.unwrap()), edit_and_add);
+        assert_eq!(store.redo().await.unwrap(), None);
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_all(&mut conn).await.unwrap(),
+            vec![(id, job("Senior Engineer"))]
+        );
+        assert_eq!(model::Resume::get_all(&mut conn).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn undo_and_redo_fail_on_changes_saved_since_unless_rebasing() {
+        let pool = test_pool().await;
+        let mut store = Store::new(pool.clone());
+        let key = store.add_new(job("Engineer"));
+        let id = store
+            .save()
+            .await
+            .unwrap()
+            .get::<model::Job>(key)
+            .db_id()
+            .unwrap();
+        let retitle = |title: &'static str| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query("UPDATE Job SET job_title=$1 WHERE id=$2;")
+                    .bind(title)
+                    .bind(id)
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+            }
+        };
+
+        // edited outside of any save since
+        retitle("Senior Engineer").await;
+        let e = store.undo().await.unwrap_err();
+        let conflict = Conflict {
+            model: "Job",
+            id,
+            deleted: false,
+        };
+        assert_eq!(
+            e.downcast::<Conflicts>().unwrap(),
+            Conflicts(vec![conflict.clone()])
+        );
+        let mut conn = pool.acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_all(&mut conn).await.unwrap(),
+            vec![(id, job("Senior Engineer"))]
+        );
+        drop(conn);
+
+        let mut store = store.rebase(true);
+        assert!(store.undo().await.unwrap().is_some());
+        let mut conn = pool.acquire().await.unwrap();
+        assert!(model::Job::get_all(&mut conn).await.unwrap().is_empty());
+        drop(conn);
+
+        // re-added outside of any save since
+        let mut conn = pool.acquire().await.unwrap();
+        job("Manager").insert(&mut conn, Some(id)).await.unwrap();
+        drop(conn);
+        let mut store = store.rebase(false);
+        let e = store.redo().await.unwrap_err();
+        assert_eq!(
+            e.downcast::<Conflicts>().unwrap(),
+            Conflicts(vec![conflict])
+        );
+        let mut store = store.rebase(true);
+        assert!(store.redo().await.unwrap().is_some());
+        let mut conn = pool.acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_all(&mut conn).await.unwrap(),
+            vec![(id, job("Engineer"))]
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_cascades_and_can_be_undone() {
+        let mut store = Store::new(test_pool().await);
+        let job_key = store.add_new(job("Engineer"));
+        let bullet = model::DescriptionItem::new(model::Parent::Job(job_key), 0, "Shipped".into());
+        let bullet_key = store.add_new(bullet.clone());
+        let keys = store.save().await.unwrap();
+        let job_id = keys.get::<model::Job>(job_key).db_id().unwrap();
+        let bullet_id = keys
+            .get::<model::DescriptionItem>(bullet_key)
+            .db_id()
+            .unwrap();
+
+        // undone before it's saved, the delete only ever touches state
+        Remove::<model::Job, Key>::remove(&mut store, Key::Db(job_id));
+        let cached: Option<&model::DescriptionItem> = store.current.cached(&Key::Db(bullet_id));
+        assert_eq!(cached, None);
+        // & isn't loaded back from the db, where it's still saved
+        let got: Option<&model::Job> = store.get(&Key::Db(job_id)).await.unwrap();
+        assert_eq!(got, None);
+        let got: Option<&model::DescriptionItem> = store.get(&Key::Db(bullet_id)).await.unwrap();
+        assert_eq!(got, None);
+        assert_eq!(store.undo().await.unwrap(), Some(Step::Unsaved));
+        let cached: Option<&model::Job> = store.current.cached(&Key::Db(job_id));
+        assert_eq!(cached, Some(&job("Engineer")));
+        let got: Option<&model::DescriptionItem> = store.get(&Key::Db(bullet_id)).await.unwrap();
+        assert_eq!(got.map(|item| item.text.as_str()), Some("Shipped"));
+
+        Remove::<model::Job, Key>::remove(&mut store, Key::Db(job_id));
+        store.save().await.unwrap();
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert!(model::Job::get_all(&mut conn).await.unwrap().is_empty());
+        assert!(model::DescriptionItem::get_all(&mut conn)
+            .await
+            .unwrap()
+            .is_empty());
+        drop(conn);
+
+        // undoing the saved delete puts back the bullet it cascaded to as well
+        let Some(Step::Saved(undone)) = store.undo().await.unwrap() else {
+            panic!("the saved delete should've been undone");
+        };
+        assert_eq!(undone.changes.len(), 1);
+        assert_eq!(
+            undone.changes[0].to_string(),
+            format!("deleted Job {job_id}")
+        );
+        assert_eq!(undone.rows, 2);
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_all(&mut conn).await.unwrap(),
+            vec![(job_id, job("Engineer"))]
+        );
+        let mut restored = bullet;
+        restored.parent = model::Parent::Job(Key::Db(job_id));
+        assert_eq!(
+            model::DescriptionItem::get_all(&mut conn).await.unwrap(),
+            vec![(bullet_id, restored)]
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_records_rows_it_unlinks() {
+        let mut store = Store::new(test_pool().await);
+        let skill_key = store.add_new(model::Skill::new("Rust".into(), None));
+        let mut engineer = job("Engineer");
+        engineer.skills.push(skill_key);
+        let job_key = store.add_new(engineer);
+        let keys = store.save().await.unwrap();
+        let skill_id = keys.get::<model::Skill>(skill_key).db_id().unwrap();
+        let job_id = keys.get::<model::Job>(job_key).db_id().unwrap();
+
+        Remove::<model::Skill, Key>::remove(&mut store, Key::Db(skill_id));
+        store.save().await.unwrap();
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert!(model::Job::get_one_by_id(&mut conn, job_id)
+            .await
+            .unwrap()
+            .skills
+            .is_empty());
+        drop(conn);
+
+        // the job lost the skill w/o being deleted itself, so undoing has to put it back too
+        let Some(Step::Saved(undone)) = store.undo().await.unwrap() else {
+            panic!("the saved delete should've been undone");
+        };
+        assert_eq!(undone.rows, 2);
+        let mut conn = store.pool().acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_one_by_id(&mut conn, job_id)
+                .await
+                .unwrap()
+                .skills,
+            vec![Key::Db(skill_id)]
+        );
+    }
+
+    #[tokio::test]
+    async fn journal_logs_saved_changes_and_what_they_replaced() {
+        let mut store = Store::new(test_pool().await);
+        let key = store.add_new(job("Engineer"));
+        let id = store
+            .save()
+            .await
+            .unwrap()
+            .get::<model::Job>(key)
+            .db_id()
+            .unwrap();
+        store.replace(Key::Db(id), job("Senior Engineer"));
+        store.save().await.unwrap();
+        store.add_new(model::Resume::new("Backend".into()));
+        store.save().await.unwrap();
+
+        let mut conn = store.pool().acquire().await.unwrap();
+        let journal = history::journal(&mut conn, Some(("job", id)))
+            .await
+            .unwrap();
+        assert_eq!(journal.len(), 2, "only changes to the job should be read");
+        assert!(journal.iter().all(|entry| entry.session == store.session));
+        let edit = &journal[1];
+        assert!(matches!(edit.change, Logged::Replace { .. }));
+        let row = &edit.rows[0];
+        assert_eq!(row.before.as_ref().unwrap()["title"], "Engineer");
+        assert_eq!(row.after.as_ref().unwrap()["title"], "Senior Engineer");
+    }
+
+    #[tokio::test]
+    async fn journal_logs_what_undoing_and_redoing_put_back() {
+        let mut store = Store::new(test_pool().await);
+        let key = store.add_new(job("Engineer"));
+        let id = store
+            .save()
+            .await
+            .unwrap()
+            .get::<model::Job>(key)
+            .db_id()
+            .unwrap();
+        store.replace(Key::Db(id), job("Senior Engineer"));
+        store.save().await.unwrap();
+        store.undo().await.unwrap();
+        store.redo().await.unwrap();
+
+        let mut conn = store.pool().acquire().await.unwrap();
+        let journal = history::journal(&mut conn, Some(("job", id)))
+            .await
+            .unwrap();
+        assert_eq!(journal.len(), 4);
+        let titles = |entry: &history::JournalEntry| {
+            let row = &entry.rows[0];
+            (
+                row.before.as_ref().unwrap()["title"].clone(),
+                row.after.as_ref().unwrap()["title"].clone(),
+            )
+        };
+        assert_eq!(journal[2].change, Logged::Undo { changes: 1 });
+        assert_eq!(
+            titles(&journal[2]),
+            ("Senior Engineer".into(), "Engineer".into())
+        );
+        assert_eq!(journal[3].change, Logged::Redo { changes: 1 });
+        assert_eq!(
+            titles(&journal[3]),
+            ("Engineer".into(), "Senior Engineer".into())
+        );
+        // reverts aren't undone themselves
+        assert!(!journal[2].undone && !journal[3].undone);
+    }
+
+    #[tokio::test]
+    async fn compacting_folds_changes_without_changing_their_effect() {
+        let mut store = Store::new(test_pool().await);
+        let saved = store.add_new(job("Intern"));
+        let saved = store.save().await.unwrap().get::<model::Job>(saved);
+        let _: Option<&model::Job> = store.get(&saved).await.unwrap();
+
+        let engineer = store.add_new(job("Engineer"));
+        store.replace(engineer, job("Senior Engineer"));
+        store.replace(saved, job("Contractor"));
+        store.replace(engineer, job("Staff Engineer"));
+        let scrapped = store.add_new(job("Manager"));
+        store.replace(scrapped, job("Director"));
+        Remove::<model::Job, Key>::remove(&mut store, scrapped);
+        let kept = store.add_new(job("Architect"));
+        store.replace(saved, job("Consultant"));
+        let mut uncompacted = store.initial.clone();
+        let before = store.changes.apply_all(&mut uncompacted);
+
+        store.changes.compact(&store.initial);
+
+        // the engineer gets one change & the scrapped job just holds it's key, but the last edit
+        // to the saved job can't move back past adding another
+        assert_eq!(store.changes.iter().count(), 5);
+        let mut compacted = store.initial.clone();
+        let after = store.changes.apply_all(&mut compacted);
+        assert!(compacted.diff(&uncompacted).is_empty());
+        assert!(uncompacted.diff(&compacted).is_empty());
+        assert_eq!(after, vec![engineer, saved, scrapped, kept, saved]);
+        assert_eq!(before[before.len() - 2], kept);
+        assert_eq!(
+            compacted.cached::<model::Job>(&saved),
+            Some(&job("Consultant"))
+        );
+    }
+
+    #[tokio::test]
+    async fn save_journals_each_values_end_result() {
+        let mut store = Store::new(test_pool().await);
+        let engineer = store.add_new(job("Engineer"));
+        store.replace(engineer, job("Senior Engineer"));
+        let scrapped = store.add_new(job("Manager"));
+        Remove::<model::Job, Key>::remove(&mut store, scrapped);
+        let kept = store.add_new(job("Architect"));
+        let keys = store.save().await.unwrap();
+
+        let mut conn = store.pool().acquire().await.unwrap();
+        let journal = history::journal(&mut conn, None).await.unwrap();
+        assert_eq!(journal.len(), 2);
+        assert!(matches!(
+            &journal[0].change,
+            Logged::Add { value, .. } if value["title"] == "Senior Engineer"
+        ));
+        let titles: Vec<_> = model::Job::get_all(&mut conn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(_, job)| job.title)
+            .collect();
+        assert_eq!(titles, vec!["Senior Engineer", "Architect"]);
+        assert!(keys.get::<model::Job>(kept).db_id().is_ok());
+    }
+
+    #[tokio::test]
+    async fn status_lists_changes_since_the_last_save() {
+        let mut store = Store::new(test_pool().await);
+        let engineer = store.add_new(job("Engineer"));
+        let resume = store.add_new(model::Resume::new("Backend".into()));
+        let keys = store.save().await.unwrap();
+        let engineer = keys.get::<model::Job>(engineer);
+        let resume = keys.get::<model::Resume>(resume);
+        assert!(store.status().is_empty());
+
+        // loading a saved value isn't a change, but editing it is
+        let _: Option<&model::Resume> = store.get(&resume).await.unwrap();
+        let _: Option<&model::Job> = store.get(&engineer).await.unwrap();
+        store.replace(engineer, job("Senior Engineer"));
+        let added = store.add_new(job("Manager"));
+        Remove::<model::Resume, Key>::remove(&mut store, resume);
+
+        assert_eq!(
+            store.status(),
+            vec![
+                ("Resume", resume, Status::Deleted),
+                ("Job", added, Status::Added),
+                ("Job", engineer, Status::Modified),
+            ]
+        );
+    }
+
+    /// Two stores opened on the same db, each having read the job saved under `key`, as two runs of
+    /// the app at once would.
+    async fn racing_stores(pool: SqlitePool, key: Key) -> (Store, Store) {
+        let mut ours = Store::open(pool.clone()).await.unwrap();
+        let mut theirs = Store::open(pool).await.unwrap();
+        let _: Option<&model::Job> = ours.get(&key).await.unwrap();
+        let _: Option<&model::Job> = theirs.get(&key).await.unwrap();
+        (ours, theirs)
+    }
+
+    #[tokio::test]
+    async fn save_fails_on_changes_to_values_saved_by_someone_else_since() {
+        let mut store = Store::new(test_pool().await);
+        let engineer = store.add_new(job("Engineer"));
+        let manager = store.add_new(job("Manager"));
+        let keys = store.save().await.unwrap();
+        let engineer = keys.get::<model::Job>(engineer);
+        let manager = keys.get::<model::Job>(manager);
+        let (mut ours, mut theirs) = racing_stores(store.pool().clone(), engineer).await;
+        let _: Option<&model::Job> = ours.get(&manager).await.unwrap();
+
+        theirs.replace(engineer, job("Staff Engineer"));
+        Remove::<model::Job, Key>::remove(&mut theirs, manager);
+        theirs.save().await.unwrap();
+        ours.replace(engineer, job("Senior Engineer"));
+        ours.replace(manager, job("Director"));
+        let e = ours.save().await.unwrap_err();
+
+        assert_eq!(
+            e.downcast::<Conflicts>().unwrap(),
+            Conflicts(vec![
+                Conflict {
+                    model: "Job",
+                    id: engineer.db_id().unwrap(),
+                    deleted: false,
+                },
+                Conflict {
+                    model: "Job",
+                    id: manager.db_id().unwrap(),
+                    deleted: true,
+                },
+            ])
+        );
+        // nothing was saved over theirs
+        let mut conn = ours.pool().acquire().await.unwrap();
+        assert_eq!(
+            model::Job::get_all(&mut conn).await.unwrap(),
+            vec![(engineer.db_id().unwrap(), job("Staff Engineer"))]
+        );
+    }
+
+    #[tokio::test]
+    async fn save_keeps_changes_to_different_values_saved_by_someone_else_since() {
+        let mut store = Store::new(test_pool().await);
+        let engineer = store.add_new(job("Engineer"));
+        let keys = store.save().await.unwrap();
+        let engineer = keys.get::<model::Job>(engineer);
+        let (mut ours, mut theirs) = racing_stores(store.pool().clone(), engineer).await;
+
+        theirs.replace(engineer, job("Senior Engineer"));
+        theirs.save().await.unwrap();
+        ours.add_new(job("Manager"));
+        ours.save().await.unwrap();
+
+        let mut conn = ours.pool().acquire().await.unwrap();
+        let titles: Vec<_> = model::Job::get_all(&mut conn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(_, job)| job.title)
+            .collect();
+        assert_eq!(titles, vec!["Senior Engineer", "Manager"]);
+    }
+
+    #[tokio::test]
+    async fn rebasing_applies_changed_fields_over_values_saved_since() {
+        let mut store = Store::new(test_pool().await);
+        let engineer = store.add_new(job("Engineer"));
+        let keys = store.save().await.unwrap();
+        let engineer = keys.get::<model::Job>(engineer);
+        let (ours, mut theirs) = racing_stores(store.pool().clone(), engineer).await;
+        let mut ours = ours.rebase(true);
+
+        let mut promoted = job("Engineer");
+        promoted.employer = "Initech".into();
+        theirs.replace(engineer, promoted);
+        theirs.save().await.unwrap();
+        ours.replace(engineer, job("Senior Engineer"));
+        ours.save().await.unwrap();
+
+        let mut conn = ours.pool().acquire().await.unwrap();
+        let saved = model::Job::get_one_by_id(&mut conn, engineer.db_id().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(saved.employer, "Initech");
+        assert_eq!(saved.title, "Senior Engineer");
+    }
 }
@@ -3,25 +3,47 @@
 // TODO:
 // - [x] impl peek
 // - [x] impl + operator
-// - [ ] impl get_all
-// Maybe?:
-// - [ ] impl pluck
-// - [ ] impl pluck_all
+// - [x] impl get_all
+// - [x] impl map & fold
+// - [x] impl pluck
+// - [x] impl pluck_all
+// - [x] impl insert by type
+// - [x] impl reverse
+// - [x] impl append
+// - [x] derive conversion of structs to & from lists of their fields
+// - [x] impl Serialize & Deserialize
 
-use std::{any::Any, fmt::Debug, marker::PhantomData, ops::Add};
+use std::{
+    any::Any,
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Add,
+};
+
+use serde::{
+    de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+pub use ttuple_derive::Generic;
 
 /// Core Ttuple list behaviors.
 ///
 /// ## Examples
 ///
 /// TODO:
-/// - [ ] Add an item to a list
-/// - [ ] Get the length of a list
-/// - [ ] Iterate over a list?
+/// - [x] Add an item to a list, see [`HList::prepend`] & [`Append`]
+/// - [x] Get the length of a list, see [`HList::len`]
+/// - [x] Iterate over a list, see [`Map`] & [`Fold`]
 pub trait HList: Sized + Debug + Eq {
     /// An HList knows it's length
     fn len(&self) -> usize;
 
+    /// An HList knows when it has no items, i.e. it's [`Nil`]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// An HList can add an item to the collection
     fn prepend<H: Sized>(self, h: H) -> Ttuple<H, Self> {
         Ttuple(h, self)
@@ -29,7 +51,7 @@ pub trait HList: Sized + Debug + Eq {
 }
 
 /// Nil marks the end of a list.
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Nil();
 
 impl HList for Nil {
@@ -39,11 +61,11 @@ impl HList for Nil {
 }
 
 /// All lists are built of nested Ttuple instances
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Ttuple<H: Sized, T = Nil>(H, T);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ttuple<H: Sized, T = Nil>(pub H, pub T);
 
 impl<H: Sized> Ttuple<H> {
-    fn new(head: H) -> Self {
+    pub fn new(head: H) -> Self {
         Ttuple(head, Nil())
     }
 }
@@ -55,7 +77,10 @@ impl<H: Sized + Debug + Eq, T: HList> HList for Ttuple<H, T> {
 }
 
 /// Borrow the first item from a two-tuple matching a given type
-trait Get<Select, Index> {
+///
+/// `Index` is inferred as the path to the item, [`Here`] or [`There`]s leading to it, so only needs
+/// naming where it can't be, e.g. in a generic impl.
+pub trait Get<Select, Index> {
     fn get(&self) -> &Select;
     fn get_mut(&mut self) -> &mut Select;
 }
@@ -86,13 +111,13 @@ where
 
 /// Type for matching the index when the head
 /// is the type requested by Getter::get
-struct Here {
+pub struct Here {
     _priv: (),
 }
 
 /// Type for matching the index when the type
 // requested by Getter::get is not in the head
-struct There<T> {
+pub struct There<T> {
     _priv: PhantomData<T>,
 }
 
@@ -111,6 +136,7 @@ fn can_borrow_items_by_type() {
 
 #[cfg(test)]
 #[test]
+#[allow(clippy::needless_range_loop)]
 fn can_mutably_borrow_items_by_type() {
     let tmp = Vec::from([1i32, 2, 3]);
     let mut t = Ttuple(2i32, Ttuple("first", Ttuple::new(tmp)));
@@ -126,7 +152,11 @@ fn can_mutably_borrow_items_by_type() {
     );
 }
 
-trait GetSome<Select> {
+/// Borrow the first item from a list matching a given type, if it has one.
+///
+/// Unlike [`Get`], lists without an item of the type can be checked too, though the type has to be
+/// `'static`.
+pub trait GetSome<Select> {
     fn get_some(&self) -> Option<&Select>;
     fn get_some_mut(&mut self) -> Option<&mut Select>;
 }
@@ -164,7 +194,7 @@ where
         let any_head = &self.0 as &dyn Any;
 
         match any_head.downcast_ref::<Select>() {
-            Some(selected) => Some(&selected),
+            Some(selected) => Some(selected),
             None => self.1.get_some(),
         }
     }
@@ -207,6 +237,7 @@ fn can_get_none_if_not_in_ttuple() {
 
 #[cfg(test)]
 #[test]
+#[allow(clippy::assertions_on_constants, clippy::redundant_pattern_matching)]
 fn get_some_can_be_used_to_check_if_ttuple_contains_type() {
     let t = Ttuple(1i32, Ttuple("str", Ttuple::new(false)));
 
@@ -226,50 +257,333 @@ fn get_some_can_be_used_to_check_if_ttuple_contains_type() {
     };
 }
 
-// TODO:
-// the below can't work because there's no good way to advance to the tail w/out recursing
-// think we need to make a GetAll or Iterator trait that depends on GetSome and recursively calls
-// GetSome on the tail--this'll need some way of tracking where we are still though--maybe using
-// Here & There<I>? Not sure yet, this'll take some thought...
-// #[cfg(test)]
-// #[test]
-// fn get_some_can_be_used_to_loop_over_all_of_type() {
-//     let t = Ttuple(1i32, Ttuple("str", Ttuple(false, Ttuple::new(2i32))));
-//
-//     let mut sum = 0;
-//     let mut keep_going = true;
-//     let mut count = 0;
-//
-//     loop {
-//         println!("loop iteration START");
-//         println!("sum is {sum:#?}");
-//         println!("count is {count:#?}");
-//         let int = t.get_some();
-//         println!("int is {int:#?}");
-//         if let Some(i) = int {
-//             println!("inside if let Some(int) w/ {i:#?}");
-//             sum += i;
-//         } else {
-//             keep_going = false;
-//         }
-//         println!("loop iteration END");
-//
-//         count += 1;
-//
-//         if !keep_going || count > 10 {
-//             println!("loop BREAK");
-//             println!("sum is {sum:#?}");
-//             println!("count is {count:#?}");
-//             break;
-//         }
-//     }
-//
-//     assert_eq!(sum, 3);
-// }
+/// Borrow every item in the list of a given type, in order, e.g. `t.get_all::<i32>()`.
+///
+/// Unlike [`Get`], which item is which can't be worked out from an index, as any item could be
+/// skipped, so each is checked as it's reached, requiring the type be `'static`. That check needs
+/// nothing from the list's type, so the type selected is a parameter of each method rather than
+/// the trait, letting it be named at the call.
+pub trait GetAll {
+    /// Push every item of the type onto the end of `all`, in order.
+    fn push_all<'a, Select: 'static>(&'a self, all: &mut Vec<&'a Select>);
+    fn push_all_mut<'a, Select: 'static>(&'a mut self, all: &mut Vec<&'a mut Select>);
+
+    fn get_all<Select: 'static>(&self) -> Vec<&Select> {
+        let mut all = Vec::new();
+        self.push_all(&mut all);
+        all
+    }
+
+    fn get_all_mut<Select: 'static>(&mut self) -> Vec<&mut Select> {
+        let mut all = Vec::new();
+        self.push_all_mut(&mut all);
+        all
+    }
+}
+
+impl GetAll for Nil {
+    fn push_all<'a, Select: 'static>(&'a self, _all: &mut Vec<&'a Select>) {}
+
+    fn push_all_mut<'a, Select: 'static>(&'a mut self, _all: &mut Vec<&'a mut Select>) {}
+}
+
+impl<Head, Tail> GetAll for Ttuple<Head, Tail>
+where
+    Head: Any,
+    Tail: HList + GetAll,
+{
+    fn push_all<'a, Select: 'static>(&'a self, all: &mut Vec<&'a Select>) {
+        all.extend((&self.0 as &dyn Any).downcast_ref::<Select>());
+        self.1.push_all(all);
+    }
+
+    fn push_all_mut<'a, Select: 'static>(&'a mut self, all: &mut Vec<&'a mut Select>) {
+        all.extend((&mut self.0 as &mut dyn Any).downcast_mut::<Select>());
+        self.1.push_all_mut(all);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn get_all_can_be_used_to_loop_over_all_of_type() {
+    let t = Ttuple(1i32, Ttuple("str", Ttuple(false, Ttuple::new(2i32))));
+
+    let ints = t.get_all::<i32>();
+    assert_eq!(ints, vec![&1, &2]);
+    assert_eq!(ints.into_iter().sum::<i32>(), 3);
+
+    let none: Vec<&String> = t.get_all();
+    assert!(none.is_empty(), "{t:#?} does not contain a String");
+}
+
+#[cfg(test)]
+#[test]
+fn get_all_mut_can_alter_all_of_type() {
+    let mut t = Ttuple(1i32, Ttuple("str", Ttuple::new(2i32)));
+
+    for i in t.get_all_mut::<i32>() {
+        *i *= 10;
+    }
+
+    assert_eq!(t, Ttuple(10i32, Ttuple("str", Ttuple::new(20i32))));
+}
+
+/// A function that can be called with items of more than one type, for mapping or folding over
+/// a list holding them.
+///
+/// Implement it once for each type of item the function handles, or generically for any.
+pub trait Func<Input> {
+    type Output;
+
+    fn call(&mut self, input: Input) -> Self::Output;
+}
+
+/// Call a function with each item in a list, in order, giving a list of the results.
+///
+/// Lists can be mapped over by value, or borrowed to map over references to each item.
+pub trait Map<F> {
+    type Output;
+
+    fn map(self, f: F) -> Self::Output;
+}
+
+impl<F> Map<F> for Nil {
+    type Output = Nil;
+
+    fn map(self, _f: F) -> Nil {
+        Nil()
+    }
+}
+
+impl<F> Map<F> for &Nil {
+    type Output = Nil;
+
+    fn map(self, _f: F) -> Nil {
+        Nil()
+    }
+}
+
+impl<F> Map<F> for &mut Nil {
+    type Output = Nil;
+
+    fn map(self, _f: F) -> Nil {
+        Nil()
+    }
+}
+
+impl<F, Head, Tail> Map<F> for Ttuple<Head, Tail>
+where
+    F: Func<Head>,
+    Tail: Map<F>,
+{
+    type Output = Ttuple<F::Output, Tail::Output>;
+
+    fn map(self, mut f: F) -> Self::Output {
+        let head = f.call(self.0);
+        Ttuple(head, self.1.map(f))
+    }
+}
+
+impl<'a, F, Head, Tail> Map<F> for &'a Ttuple<Head, Tail>
+where
+    F: Func<&'a Head>,
+    &'a Tail: Map<F>,
+{
+    type Output = Ttuple<F::Output, <&'a Tail as Map<F>>::Output>;
+
+    fn map(self, mut f: F) -> Self::Output {
+        let head = f.call(&self.0);
+        Ttuple(head, self.1.map(f))
+    }
+}
+
+impl<'a, F, Head, Tail> Map<F> for &'a mut Ttuple<Head, Tail>
+where
+    F: Func<&'a mut Head>,
+    &'a mut Tail: Map<F>,
+{
+    type Output = Ttuple<F::Output, <&'a mut Tail as Map<F>>::Output>;
+
+    fn map(self, mut f: F) -> Self::Output {
+        let Ttuple(head, tail) = self;
+        let head = f.call(head);
+        Ttuple(head, tail.map(f))
+    }
+}
+
+/// Combine every item in a list into one value, calling a function with the value so far & each
+/// item in turn, in order.
+///
+/// Like [`Map`], lists can be folded by value or by reference.
+pub trait Fold<F, Acc> {
+    fn fold(self, init: Acc, f: F) -> Acc;
+}
+
+impl<F, Acc> Fold<F, Acc> for Nil {
+    fn fold(self, init: Acc, _f: F) -> Acc {
+        init
+    }
+}
+
+impl<F, Acc> Fold<F, Acc> for &Nil {
+    fn fold(self, init: Acc, _f: F) -> Acc {
+        init
+    }
+}
+
+impl<F, Acc> Fold<F, Acc> for &mut Nil {
+    fn fold(self, init: Acc, _f: F) -> Acc {
+        init
+    }
+}
+
+impl<F, Acc, Head, Tail> Fold<F, Acc> for Ttuple<Head, Tail>
+where
+    F: Func<(Acc, Head), Output = Acc>,
+    Tail: Fold<F, Acc>,
+{
+    fn fold(self, init: Acc, mut f: F) -> Acc {
+        let acc = f.call((init, self.0));
+        self.1.fold(acc, f)
+    }
+}
+
+impl<'a, F, Acc, Head, Tail> Fold<F, Acc> for &'a Ttuple<Head, Tail>
+where
+    F: Func<(Acc, &'a Head), Output = Acc>,
+    &'a Tail: Fold<F, Acc>,
+{
+    fn fold(self, init: Acc, mut f: F) -> Acc {
+        let acc = f.call((init, &self.0));
+        self.1.fold(acc, f)
+    }
+}
+
+impl<'a, F, Acc, Head, Tail> Fold<F, Acc> for &'a mut Ttuple<Head, Tail>
+where
+    F: Func<(Acc, &'a mut Head), Output = Acc>,
+    &'a mut Tail: Fold<F, Acc>,
+{
+    fn fold(self, init: Acc, mut f: F) -> Acc {
+        let Ttuple(head, tail) = self;
+        let acc = f.call((init, head));
+        tail.fold(acc, f)
+    }
+}
+
+#[cfg(test)]
+mod test_map_fold {
+    use super::*;
+
+    /// Describes any item it's given.
+    struct Describe;
+
+    impl<T: Debug> Func<T> for Describe {
+        type Output = String;
+
+        fn call(&mut self, input: T) -> String {
+            format!("{input:?}")
+        }
+    }
+
+    /// Doubles numbers, & repeats strings.
+    struct Double;
+
+    impl Func<i32> for Double {
+        type Output = i32;
+
+        fn call(&mut self, input: i32) -> i32 {
+            input * 2
+        }
+    }
+
+    impl Func<&str> for Double {
+        type Output = String;
+
+        fn call(&mut self, input: &str) -> String {
+            input.repeat(2)
+        }
+    }
+
+    impl Func<&mut i32> for Double {
+        type Output = ();
+
+        fn call(&mut self, input: &mut i32) {
+            *input *= 2;
+        }
+    }
+
+    /// Counts the items it's given, whatever they are.
+    struct Count;
+
+    impl<T> Func<(usize, T)> for Count {
+        type Output = usize;
+
+        fn call(&mut self, (count, _): (usize, T)) -> usize {
+            count + 1
+        }
+    }
+
+    /// Adds up numbers, counting each string by it's length.
+    struct Sum;
+
+    impl Func<(usize, &usize)> for Sum {
+        type Output = usize;
+
+        fn call(&mut self, (sum, n): (usize, &usize)) -> usize {
+            sum + n
+        }
+    }
+
+    impl Func<(usize, &&str)> for Sum {
+        type Output = usize;
+
+        fn call(&mut self, (sum, s): (usize, &&str)) -> usize {
+            sum + s.len()
+        }
+    }
+
+    #[test]
+    fn map_calls_a_function_on_each_item() {
+        let t = Ttuple(1i32, Ttuple("ab", Ttuple::new(3i32)));
+
+        assert_eq!(
+            t.map(Double),
+            Ttuple(2i32, Ttuple("abab".to_string(), Ttuple::new(6i32)))
+        );
+        assert_eq!(
+            (&t).map(Describe),
+            Ttuple(
+                "1".to_string(),
+                Ttuple("\"ab\"".to_string(), Ttuple::new("3".to_string()))
+            )
+        );
+        assert_eq!(Nil().map(Describe), Nil());
+    }
+
+    #[test]
+    fn map_can_alter_items_in_place() {
+        let mut t = Ttuple(1i32, Ttuple::new(2i32));
+
+        (&mut t).map(Double);
+
+        assert_eq!(t, Ttuple(2i32, Ttuple::new(4i32)));
+    }
+
+    #[test]
+    fn fold_combines_every_item() {
+        let t = Ttuple(1usize, Ttuple("abc", Ttuple(false, Ttuple::new(2usize))));
+        assert_eq!(t.fold(0, Count), 4);
+
+        let sized = Ttuple(1usize, Ttuple("abc", Ttuple::new(2usize)));
+        assert_eq!((&sized).fold(0, Sum), 6);
+        assert_eq!(Nil().fold(0, Count), 0);
+    }
+}
 
 /// Destructively remove first item in list, returning it along with a new Ttuple made from the
 /// tail of the list
-trait Pop<H, T> {
+pub trait Pop<H, T> {
     fn pop(self) -> (H, T);
 }
 
@@ -293,7 +607,7 @@ fn can_pop_head() {
 }
 
 /// Look at the first item on the list by borrowing it without altering the list
-trait Peek<H> {
+pub trait Peek<H> {
     fn peek(&self) -> &H;
 }
 
@@ -409,3 +723,708 @@ fn ttuple_extends_ttuple() {
         )
     );
 }
+
+/// Remove the item of a given type from anywhere in the list, returning it along with a new list of
+/// everything else, in order.
+///
+/// Like [`Get`], `Index` is inferred as the path to the item, so can only be left out when the type
+/// appears in the list once.
+pub trait Pluck<Select, Index> {
+    type Remainder;
+
+    fn pluck(self) -> (Select, Self::Remainder);
+}
+
+impl<FromHead, Tail> Pluck<FromHead, Here> for Ttuple<FromHead, Tail> {
+    type Remainder = Tail;
+
+    fn pluck(self) -> (FromHead, Tail) {
+        (self.0, self.1)
+    }
+}
+
+impl<Head, Tail, FromTail, Index> Pluck<FromTail, There<Index>> for Ttuple<Head, Tail>
+where
+    Tail: Pluck<FromTail, Index>,
+{
+    type Remainder = Ttuple<Head, Tail::Remainder>;
+
+    fn pluck(self) -> (FromTail, Self::Remainder) {
+        let (selected, tail) = self.1.pluck();
+        (selected, Ttuple(self.0, tail))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn can_pluck_items_by_type() {
+    let t = Ttuple(2i32, Ttuple("first", Ttuple::new(Some(false))));
+
+    let (s, rest): (&str, _) = t.pluck();
+    assert_eq!(s, "first");
+    assert_eq!(rest, Ttuple(2i32, Ttuple::new(Some(false))));
+
+    let (b, rest): (Option<bool>, _) = rest.pluck();
+    assert_eq!(b, Some(false));
+    assert_eq!(rest, Ttuple::new(2i32));
+}
+
+/// Remove an item of each type in a list of them, returning them as that list along with a new list
+/// of everything else, in order.
+///
+/// Each type is plucked in turn with [`Pluck`], so the items needn't be in the same order as they are
+/// in the source list.
+pub trait PluckAll<Selected, Indices> {
+    type Remainder;
+
+    fn pluck_all(self) -> (Selected, Self::Remainder);
+}
+
+impl<Source> PluckAll<Nil, Nil> for Source {
+    type Remainder = Source;
+
+    fn pluck_all(self) -> (Nil, Source) {
+        (Nil(), self)
+    }
+}
+
+impl<Source, Head, Tail, HeadIndex, TailIndices>
+    PluckAll<Ttuple<Head, Tail>, Ttuple<HeadIndex, TailIndices>> for Source
+where
+    Source: Pluck<Head, HeadIndex>,
+    Source::Remainder: PluckAll<Tail, TailIndices>,
+{
+    type Remainder = <Source::Remainder as PluckAll<Tail, TailIndices>>::Remainder;
+
+    fn pluck_all(self) -> (Ttuple<Head, Tail>, Self::Remainder) {
+        let (head, rest) = self.pluck();
+        let (tail, rest) = rest.pluck_all();
+        (Ttuple(head, tail), rest)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn can_pluck_all_of_several_types() {
+    let t = Ttuple(1i32, Ttuple("first", Ttuple(false, Ttuple::new(3u8))));
+
+    let (selected, rest): (Ttuple<bool, Ttuple<i32>>, _) = t.pluck_all();
+    assert_eq!(selected, Ttuple(false, Ttuple::new(1i32)));
+    assert_eq!(rest, Ttuple("first", Ttuple::new(3u8)));
+
+    let (none, rest): (Nil, Ttuple<&str, Ttuple<u8>>) = rest.pluck_all();
+    assert_eq!(none, Nil());
+    assert_eq!(rest, Ttuple("first", Ttuple::new(3u8)));
+}
+
+/// Insert an item just before the item of a given type, anywhere in the list, the counterpart of
+/// [`Pluck`].
+///
+/// The type to insert before can't be inferred from the item being inserted, so has to be named,
+/// e.g. `Insert::<bool, _, _>::insert(list, 1u8)`. Like [`Get`], `Index` is inferred as the path to
+/// it, so can only be left out when the type appears in the list once.
+pub trait Insert<Before, New, Index> {
+    type Output;
+
+    fn insert(self, new: New) -> Self::Output;
+}
+
+impl<Before, Tail, New> Insert<Before, New, Here> for Ttuple<Before, Tail> {
+    type Output = Ttuple<New, Self>;
+
+    fn insert(self, new: New) -> Self::Output {
+        Ttuple(new, self)
+    }
+}
+
+impl<Head, Tail, Before, New, Index> Insert<Before, New, There<Index>> for Ttuple<Head, Tail>
+where
+    Tail: Insert<Before, New, Index>,
+{
+    type Output = Ttuple<Head, Tail::Output>;
+
+    fn insert(self, new: New) -> Self::Output {
+        Ttuple(self.0, self.1.insert(new))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn can_insert_items_by_type() {
+    let t = Ttuple(2i32, Ttuple("first", Ttuple::new(Some(false))));
+
+    let t = Insert::<&str, _, _>::insert(t, 3u8);
+    assert_eq!(
+        t,
+        Ttuple(2i32, Ttuple(3u8, Ttuple("first", Ttuple::new(Some(false)))))
+    );
+
+    // inserting what was plucked puts the list back the way it was
+    let (s, rest): (&str, _) = t.pluck();
+    assert_eq!(
+        Insert::<Option<bool>, _, _>::insert(rest, s),
+        Ttuple(2i32, Ttuple(3u8, Ttuple("first", Ttuple::new(Some(false)))))
+    );
+}
+
+/// Add an item to the end of the list, the counterpart of [`HList::prepend`].
+pub trait Append<Last> {
+    type Output;
+
+    fn append(self, last: Last) -> Self::Output;
+}
+
+impl<Last> Append<Last> for Nil {
+    type Output = Ttuple<Last>;
+
+    fn append(self, last: Last) -> Self::Output {
+        Ttuple::new(last)
+    }
+}
+
+impl<H, T, Last> Append<Last> for Ttuple<H, T>
+where
+    T: Append<Last>,
+{
+    type Output = Ttuple<H, T::Output>;
+
+    fn append(self, last: Last) -> Self::Output {
+        Ttuple(self.0, self.1.append(last))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn can_append_to_tail() {
+    assert_eq!(Nil().append(1i32), Ttuple::new(1i32));
+    assert_eq!(
+        Ttuple(1i32, Ttuple::new("first")).append(false),
+        Ttuple(1i32, Ttuple("first", Ttuple::new(false)))
+    );
+}
+
+/// Reverse the order of the items in the list.
+pub trait Reverse {
+    type Output;
+
+    fn reverse(self) -> Self::Output;
+}
+
+impl Reverse for Nil {
+    type Output = Nil;
+
+    fn reverse(self) -> Nil {
+        self
+    }
+}
+
+impl<H, T> Reverse for Ttuple<H, T>
+where
+    T: Reverse,
+    T::Output: Append<H>,
+{
+    type Output = <T::Output as Append<H>>::Output;
+
+    fn reverse(self) -> Self::Output {
+        self.1.reverse().append(self.0)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn can_reverse_list() {
+    assert_eq!(Nil().reverse(), Nil());
+    assert_eq!(Ttuple::new(1i32).reverse(), Ttuple::new(1i32));
+    assert_eq!(
+        Ttuple(1i32, Ttuple("first", Ttuple::new(false))).reverse(),
+        Ttuple(false, Ttuple("first", Ttuple::new(1i32)))
+    );
+}
+
+/// A value that can be converted to a list of it's fields & back, so generic code over lists can
+/// work on it.
+///
+/// Derive it for structs with named fields using `#[derive(Generic)]`.
+pub trait Generic: Sized {
+    /// A list of the value's fields, in the order they're declared.
+    type Repr;
+
+    /// A list of the value's fields, each labelled with the name of the field.
+    type Labelled;
+
+    fn into_repr(self) -> Self::Repr;
+    fn from_repr(repr: Self::Repr) -> Self;
+    fn into_labelled(self) -> Self::Labelled;
+    fn from_labelled(labelled: Self::Labelled) -> Self;
+}
+
+/// A type naming a field, so a list of fields knows their names without having to hold them.
+pub trait Label {
+    const NAME: &'static str;
+}
+
+/// A value labelled with the name of the field it belongs in.
+pub struct Field<L, T> {
+    pub value: T,
+    label: PhantomData<L>,
+}
+
+impl<L, T> Field<L, T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            label: PhantomData,
+        }
+    }
+}
+
+impl<L: Label, T> Field<L, T> {
+    pub fn name(&self) -> &'static str {
+        L::NAME
+    }
+}
+
+impl<L: Label, T: Debug> Debug for Field<L, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &L::NAME)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<L, T: Clone> Clone for Field<L, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<L, T: PartialEq> PartialEq for Field<L, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<L, T: Eq> Eq for Field<L, T> {}
+
+#[cfg(test)]
+mod test_generic {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Generic)]
+    struct Job {
+        title: String,
+        employer: &'static str,
+        current: bool,
+    }
+
+    fn job() -> Job {
+        Job {
+            title: "Developer".to_string(),
+            employer: "Acme",
+            current: true,
+        }
+    }
+
+    #[test]
+    fn structs_convert_to_lists_of_their_fields_and_back() {
+        let repr = job().into_repr();
+
+        assert_eq!(
+            repr,
+            Ttuple("Developer".to_string(), Ttuple("Acme", Ttuple::new(true)))
+        );
+        assert_eq!(Job::from_repr(repr), job());
+    }
+
+    #[test]
+    fn labelled_fields_know_their_names() {
+        let labelled = job().into_labelled();
+
+        assert_eq!(labelled.0.name(), "title");
+        assert_eq!(labelled.1 .0.name(), "employer");
+        assert_eq!(labelled.1 .1 .0.name(), "current");
+        assert!(labelled.1 .1 .0.value);
+        assert_eq!(Job::from_labelled(labelled), job());
+    }
+
+    #[test]
+    fn raw_fields_are_named_without_the_r() {
+        #[derive(Debug, PartialEq, Generic)]
+        #[ttuple(crate = crate::ttuple)]
+        struct Bullet {
+            r#type: &'static str,
+        }
+
+        let labelled = Bullet { r#type: "variant" }.into_labelled();
+
+        assert_eq!(labelled.0.name(), "type");
+        assert_eq!(labelled.0.value, "variant");
+    }
+
+    #[test]
+    fn fields_can_be_worked_on_generically() {
+        let mut repr = job().into_repr();
+
+        *repr.get_mut() = false;
+        let (employer, rest): (&str, _) = repr.pluck();
+
+        assert_eq!(employer, "Acme");
+        assert_eq!(rest.len(), 2);
+        assert_eq!(
+            Job::from_repr(rest.append("Initech").pluck_all().0),
+            Job {
+                employer: "Initech",
+                current: false,
+                ..job()
+            }
+        );
+    }
+}
+
+/// Lists serialize as a sequence of their items, or as a map of names to values if every item is
+/// a labelled [`Field`].
+///
+/// [`Field`] can't be serialized on it's own, as it's label only means anything as part of a list.
+impl Serialize for Nil {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_tuple(0)?.end()
+    }
+}
+
+impl<H, T> Serialize for Ttuple<H, T>
+where
+    H: Serialize,
+    T: SerializeElements,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_tuple(1 + T::LEN)?;
+        seq.serialize_element(&self.0)?;
+        self.1.serialize_elements(&mut seq)?;
+        seq.end()
+    }
+}
+
+impl<L, V, T> Serialize for Ttuple<Field<L, V>, T>
+where
+    L: Label,
+    V: Serialize,
+    T: SerializeEntries,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1 + T::LEN))?;
+        map.serialize_entry(L::NAME, &self.0.value)?;
+        self.1.serialize_entries(&mut map)?;
+        map.end()
+    }
+}
+
+/// Serialize each item of a list in turn as the elements of a sequence.
+pub trait SerializeElements {
+    const LEN: usize;
+
+    fn serialize_elements<S: SerializeTuple>(&self, seq: &mut S) -> Result<(), S::Error>;
+}
+
+impl SerializeElements for Nil {
+    const LEN: usize = 0;
+
+    fn serialize_elements<S: SerializeTuple>(&self, _seq: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<H: Serialize, T: SerializeElements> SerializeElements for Ttuple<H, T> {
+    const LEN: usize = 1 + T::LEN;
+
+    fn serialize_elements<S: SerializeTuple>(&self, seq: &mut S) -> Result<(), S::Error> {
+        seq.serialize_element(&self.0)?;
+        self.1.serialize_elements(seq)
+    }
+}
+
+/// Serialize each labelled field of a list in turn as the entries of a map.
+pub trait SerializeEntries {
+    const LEN: usize;
+
+    fn serialize_entries<S: SerializeMap>(&self, map: &mut S) -> Result<(), S::Error>;
+}
+
+impl SerializeEntries for Nil {
+    const LEN: usize = 0;
+
+    fn serialize_entries<S: SerializeMap>(&self, _map: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<L, V, T> SerializeEntries for Ttuple<Field<L, V>, T>
+where
+    L: Label,
+    V: Serialize,
+    T: SerializeEntries,
+{
+    const LEN: usize = 1 + T::LEN;
+
+    fn serialize_entries<S: SerializeMap>(&self, map: &mut S) -> Result<(), S::Error> {
+        map.serialize_entry(L::NAME, &self.0.value)?;
+        self.1.serialize_entries(map)
+    }
+}
+
+/// Lists deserialize from the same forms they serialize to.
+///
+/// Labelled fields may be given in any order, & any unknown names are ignored, like a derived
+/// `Deserialize` impl for a struct.
+impl<'de> Deserialize<'de> for Nil {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(0, ElementsVisitor(PhantomData))
+    }
+}
+
+impl<'de, H, T> Deserialize<'de> for Ttuple<H, T>
+where
+    H: Deserialize<'de>,
+    T: DeserializeElements<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(Self::LEN, ElementsVisitor(PhantomData))
+    }
+}
+
+impl<'de, L, V, T> Deserialize<'de> for Ttuple<Field<L, V>, T>
+where
+    L: Label,
+    V: Deserialize<'de>,
+    T: DeserializeEntries<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(EntriesVisitor(PhantomData))
+    }
+}
+
+/// Deserialize each item of a list in turn from the elements of a sequence.
+pub trait DeserializeElements<'de>: Sized {
+    const LEN: usize;
+
+    /// Take the list's items from the sequence, `index` being the position of the first of them.
+    fn deserialize_elements<A: SeqAccess<'de>>(seq: &mut A, index: usize)
+        -> Result<Self, A::Error>;
+}
+
+impl<'de> DeserializeElements<'de> for Nil {
+    const LEN: usize = 0;
+
+    fn deserialize_elements<A: SeqAccess<'de>>(
+        _seq: &mut A,
+        _index: usize,
+    ) -> Result<Self, A::Error> {
+        Ok(Nil())
+    }
+}
+
+impl<'de, H, T> DeserializeElements<'de> for Ttuple<H, T>
+where
+    H: Deserialize<'de>,
+    T: DeserializeElements<'de>,
+{
+    const LEN: usize = 1 + T::LEN;
+
+    fn deserialize_elements<A: SeqAccess<'de>>(
+        seq: &mut A,
+        index: usize,
+    ) -> Result<Self, A::Error> {
+        let head = seq.next_element()?.ok_or_else(|| {
+            de::Error::invalid_length(index, &ElementsVisitor::<Self>(PhantomData))
+        })?;
+        let tail = T::deserialize_elements(seq, index + 1)?;
+
+        Ok(Ttuple(head, tail))
+    }
+}
+
+struct ElementsVisitor<List>(PhantomData<List>);
+
+impl<'de, List: DeserializeElements<'de>> Visitor<'de> for ElementsVisitor<List> {
+    type Value = List;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} items", List::LEN)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<List, A::Error> {
+        List::deserialize_elements(&mut seq, 0)
+    }
+}
+
+/// Deserialize each labelled field of a list from the entries of a map, in whichever order they're
+/// found.
+pub trait DeserializeEntries<'de>: Sized {
+    /// Values found so far for each field.
+    type Partial: Default;
+
+    /// Deserialize the value of the entry if it's named by one of the list's fields, returning
+    /// whether it was.
+    fn deserialize_entry<A: MapAccess<'de>>(
+        partial: &mut Self::Partial,
+        name: &str,
+        map: &mut A,
+    ) -> Result<bool, A::Error>;
+
+    /// Make the list from the values found, failing if any are missing.
+    fn complete<E: de::Error>(partial: Self::Partial) -> Result<Self, E>;
+}
+
+impl<'de> DeserializeEntries<'de> for Nil {
+    type Partial = Nil;
+
+    fn deserialize_entry<A: MapAccess<'de>>(
+        _partial: &mut Nil,
+        _name: &str,
+        _map: &mut A,
+    ) -> Result<bool, A::Error> {
+        Ok(false)
+    }
+
+    fn complete<E: de::Error>(_partial: Nil) -> Result<Self, E> {
+        Ok(Nil())
+    }
+}
+
+impl<'de, L, V, T> DeserializeEntries<'de> for Ttuple<Field<L, V>, T>
+where
+    L: Label,
+    V: Deserialize<'de>,
+    T: DeserializeEntries<'de>,
+{
+    type Partial = Ttuple<Option<V>, T::Partial>;
+
+    fn deserialize_entry<A: MapAccess<'de>>(
+        partial: &mut Self::Partial,
+        name: &str,
+        map: &mut A,
+    ) -> Result<bool, A::Error> {
+        if name != L::NAME {
+            return T::deserialize_entry(&mut partial.1, name, map);
+        }
+        if partial.0.is_some() {
+            return Err(de::Error::duplicate_field(L::NAME));
+        }
+        partial.0 = Some(map.next_value()?);
+
+        Ok(true)
+    }
+
+    fn complete<E: de::Error>(partial: Self::Partial) -> Result<Self, E> {
+        let value = partial.0.ok_or_else(|| de::Error::missing_field(L::NAME))?;
+
+        Ok(Ttuple(Field::new(value), T::complete(partial.1)?))
+    }
+}
+
+struct EntriesVisitor<List>(PhantomData<List>);
+
+impl<'de, List: DeserializeEntries<'de>> Visitor<'de> for EntriesVisitor<List> {
+    type Value = List;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map of field names to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<List, A::Error> {
+        let mut partial = List::Partial::default();
+        while let Some(name) = map.next_key::<String>()? {
+            if !List::deserialize_entry(&mut partial, &name, &mut map)? {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        List::complete(partial)
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Generic)]
+    struct Job {
+        title: String,
+        years: u8,
+        current: bool,
+    }
+
+    fn job() -> Job {
+        Job {
+            title: "Developer".to_string(),
+            years: 3,
+            current: true,
+        }
+    }
+
+    #[test]
+    fn lists_serialize_as_sequences() -> serde_json::Result<()> {
+        let t = Ttuple(1i32, Ttuple("first", Ttuple::new(Some(false))));
+
+        assert_eq!(serde_json::to_string(&t)?, r#"[1,"first",false]"#);
+        assert_eq!(serde_json::to_string(&Nil())?, "[]");
+        assert_eq!(
+            serde_json::to_string(&Ttuple(Nil(), Ttuple::new(Ttuple::new(2u8))))?,
+            "[[],[2]]"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn lists_deserialize_from_sequences() -> serde_json::Result<()> {
+        let t: Ttuple<i32, Ttuple<String, Ttuple<Option<bool>>>> =
+            serde_json::from_str(r#"[1, "first", null]"#)?;
+        assert_eq!(t, Ttuple(1, Ttuple("first".to_string(), Ttuple::new(None))));
+
+        let n: Nil = serde_json::from_str("[]")?;
+        assert_eq!(n, Nil());
+
+        let short = serde_json::from_str::<Ttuple<i32, Ttuple<bool>>>("[1]");
+        assert!(short.is_err(), "{short:?} should be missing an item");
+        let long = serde_json::from_str::<Ttuple<i32, Ttuple<bool>>>("[1, true, 2]");
+        assert!(long.is_err(), "{long:?} should have an item too many");
+
+        Ok(())
+    }
+
+    #[test]
+    fn labelled_lists_serialize_as_maps() -> serde_json::Result<()> {
+        assert_eq!(
+            serde_json::to_string(&job().into_labelled())?,
+            r#"{"title":"Developer","years":3,"current":true}"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn labelled_lists_deserialize_from_maps_in_any_order() -> serde_json::Result<()> {
+        let labelled: <Job as Generic>::Labelled = serde_json::from_str(
+            r#"{"current": true, "unknown": [1, 2], "years": 3, "title": "Developer"}"#,
+        )?;
+        assert_eq!(Job::from_labelled(labelled), job());
+
+        let missing =
+            serde_json::from_str::<<Job as Generic>::Labelled>(r#"{"title": "a", "years": 3}"#);
+        assert!(
+            missing.is_err_and(|e| e.to_string().contains("missing field `current`")),
+            "current should be missing"
+        );
+
+        let duplicate = serde_json::from_str::<<Job as Generic>::Labelled>(
+            r#"{"title": "a", "years": 3, "years": 4, "current": false}"#,
+        );
+        assert!(
+            duplicate.is_err_and(|e| e.to_string().contains("duplicate field `years`")),
+            "years should be duplicated"
+        );
+
+        Ok(())
+    }
+}
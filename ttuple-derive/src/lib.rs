@@ -0,0 +1,302 @@
+//! Derive macros for res-gen's `ttuple` module
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_macro_input, parse_quote, Data, DeriveInput, Fields, Ident, Path};
+
+/// Derive `ttuple::Generic` for a struct w/ named fields, converting it to a `Ttuple` of it's
+/// fields & back, in the order they're declared.
+///
+/// A hidden label type is declared alongside the struct for each field, naming it, so the
+/// labelled list of fields knows their names.
+///
+/// Generated code refers to the module as `::res_gen::ttuple`, which can be changed with
+/// `#[ttuple(crate = path::to::ttuple)]` on the struct, e.g. where res-gen is renamed.
+#[proc_macro_derive(Generic, attributes(ttuple))]
+pub fn derive_generic(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    generic(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn generic(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Generic can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Generic can only be derived for structs",
+            ))
+        }
+    };
+
+    let ttuple = ttuple_path(input)?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents: Vec<&Ident> = fields.iter().filter_map(|f| f.ident.as_ref()).collect();
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    // raw idents, e.g. `r#type`, are named w/out the `r#`, & couldn't be part of another ident
+    let labels: Vec<Ident> = idents
+        .iter()
+        .map(|ident| format_ident!("__{}_{}", name, ident.unraw()))
+        .collect();
+    let label_names: Vec<String> = idents
+        .iter()
+        .map(|ident| ident.unraw().to_string())
+        .collect();
+
+    let repr = nest_type(&ttuple, types.iter().map(|ty| quote!(#ty)));
+    let labelled = nest_type(
+        &ttuple,
+        labels
+            .iter()
+            .zip(&types)
+            .map(|(label, ty)| quote!(#ttuple::Field<#label, #ty>)),
+    );
+    let values = nest_value(&ttuple, idents.iter().map(|ident| quote!(#ident)));
+    let fields = nest_value(
+        &ttuple,
+        idents
+            .iter()
+            .map(|ident| quote!(#ttuple::Field::new(#ident))),
+    );
+    let unlabelled = nest_value(
+        &ttuple,
+        idents
+            .iter()
+            .map(|ident| quote!(#ttuple::Field { value: #ident, .. })),
+    );
+
+    Ok(quote! {
+        #(
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #vis struct #labels;
+
+            impl #ttuple::Label for #labels {
+                const NAME: &'static str = #label_names;
+            }
+        )*
+
+        impl #impl_generics #ttuple::Generic for #name #ty_generics #where_clause {
+            type Repr = #repr;
+            type Labelled = #labelled;
+
+            fn into_repr(self) -> Self::Repr {
+                let #name { #(#idents),* } = self;
+                #values
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                let #values = repr;
+                #name { #(#idents),* }
+            }
+
+            fn into_labelled(self) -> Self::Labelled {
+                let #name { #(#idents),* } = self;
+                #fields
+            }
+
+            fn from_labelled(labelled: Self::Labelled) -> Self {
+                let #unlabelled = labelled;
+                #name { #(#idents),* }
+            }
+        }
+    })
+}
+
+/// The path to the `ttuple` module, from the struct's `#[ttuple(crate = ...)]` attribute if it has
+/// one.
+fn ttuple_path(input: &DeriveInput) -> syn::Result<Path> {
+    let mut path = parse_quote!(::res_gen::ttuple);
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("ttuple"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                path = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown ttuple attribute, expected `crate`"))
+            }
+        })?;
+    }
+
+    Ok(path)
+}
+
+/// Nest a sequence of types into the type of a list of them.
+fn nest_type(ttuple: &Path, items: impl DoubleEndedIterator<Item = TokenStream2>) -> TokenStream2 {
+    items.rev().fold(
+        quote!(#ttuple::Nil),
+        |tail, head| quote!(#ttuple::Ttuple<#head, #tail>),
+    )
+}
+
+/// Nest a sequence of expressions or patterns into a list of them.
+fn nest_value(ttuple: &Path, items: impl DoubleEndedIterator<Item = TokenStream2>) -> TokenStream2 {
+    items.rev().fold(
+        quote!(#ttuple::Nil()),
+        |tail, head| quote!(#ttuple::Ttuple(#head, #tail)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn expand(input: TokenStream2) -> syn::Result<String> {
+        generic(&syn::parse2(input)?).map(|expanded| expanded.to_string())
+    }
+
+    #[test]
+    fn expands_to_labels_and_a_generic_impl() {
+        let expanded = expand(quote! {
+            #[ttuple(crate = crate::ttuple)]
+            pub struct Point {
+                x: i32,
+                y: i32,
+            }
+        })
+        .unwrap();
+
+        let expected = quote! {
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct __Point_x;
+
+            impl crate::ttuple::Label for __Point_x {
+                const NAME: &'static str = "x";
+            }
+
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct __Point_y;
+
+            impl crate::ttuple::Label for __Point_y {
+                const NAME: &'static str = "y";
+            }
+
+            impl crate::ttuple::Generic for Point {
+                type Repr = crate::ttuple::Ttuple<i32, crate::ttuple::Ttuple<i32, crate::ttuple::Nil>>;
+                type Labelled = crate::ttuple::Ttuple<
+                    crate::ttuple::Field<__Point_x, i32>,
+                    crate::ttuple::Ttuple<crate::ttuple::Field<__Point_y, i32>, crate::ttuple::Nil>
+                >;
+
+                fn into_repr(self) -> Self::Repr {
+                    let Point { x, y } = self;
+                    crate::ttuple::Ttuple(x, crate::ttuple::Ttuple(y, crate::ttuple::Nil()))
+                }
+
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let crate::ttuple::Ttuple(x, crate::ttuple::Ttuple(y, crate::ttuple::Nil())) =
+                        repr;
+                    Point { x, y }
+                }
+
+                fn into_labelled(self) -> Self::Labelled {
+                    let Point { x, y } = self;
+                    crate::ttuple::Ttuple(
+                        crate::ttuple::Field::new(x),
+                        crate::ttuple::Ttuple(crate::ttuple::Field::new(y), crate::ttuple::Nil())
+                    )
+                }
+
+                fn from_labelled(labelled: Self::Labelled) -> Self {
+                    let crate::ttuple::Ttuple(
+                        crate::ttuple::Field { value: x, .. },
+                        crate::ttuple::Ttuple(crate::ttuple::Field { value: y, .. }, crate::ttuple::Nil())
+                    ) = labelled;
+                    Point { x, y }
+                }
+            }
+        };
+        // reprinted so tokens split differently, e.g. `>>` & `> >`, still compare equal
+        let expanded: syn::File = syn::parse_str(&expanded).unwrap();
+        let expected: syn::File = syn::parse2(expected).unwrap();
+        assert_eq!(quote!(#expanded).to_string(), quote!(#expected).to_string());
+    }
+
+    #[test]
+    fn refers_to_res_gen_by_default() {
+        let expanded = expand(quote!(
+            struct Point {
+                x: i32,
+            }
+        ))
+        .unwrap();
+
+        assert!(expanded.contains(&quote!(impl ::res_gen::ttuple::Generic for Point).to_string()));
+        assert!(!expanded.contains("crate :: ttuple"));
+    }
+
+    #[test]
+    fn raw_fields_are_labelled_without_the_r() {
+        let expanded = expand(quote!(
+            struct Job {
+                r#type: String,
+            }
+        ))
+        .unwrap();
+
+        assert!(expanded.contains("struct __Job_type ;"));
+        assert!(expanded.contains("const NAME : & 'static str = \"type\""));
+        assert!(expanded.contains("let Job { r#type } = self ;"));
+        // it has to still be valid rust
+        syn::parse_str::<syn::File>(&expanded).unwrap();
+    }
+
+    #[test]
+    fn only_structs_with_named_fields_can_derive() {
+        let tuple = expand(quote!(
+            struct Point(i32, i32);
+        ))
+        .unwrap_err();
+        let unit = expand(quote!(
+            struct Origin;
+        ))
+        .unwrap_err();
+        let variants = expand(quote!(
+            enum Axis {
+                X,
+                Y,
+            }
+        ))
+        .unwrap_err();
+        let attribute = expand(quote! {
+            #[ttuple(path = crate::ttuple)]
+            struct Point { x: i32 }
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            tuple.to_string(),
+            "Generic can only be derived for structs with named fields"
+        );
+        assert_eq!(unit.to_string(), tuple.to_string());
+        assert_eq!(
+            variants.to_string(),
+            "Generic can only be derived for structs"
+        );
+        assert_eq!(
+            attribute.to_string(),
+            "unknown ttuple attribute, expected `crate`"
+        );
+    }
+}